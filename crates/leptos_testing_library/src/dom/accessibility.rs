@@ -0,0 +1,320 @@
+use leptos::prelude::document;
+use leptos::prelude::window;
+
+use super::*;
+
+/// Compute the accessible name of an element, the text a screen reader
+/// announces for it. This follows the [accname 1.2] algorithm: in order of
+/// priority the name comes from `aria-labelledby`, `aria-label`, the host
+/// language (associated `<label>`s, `alt`, `<legend>`, `<caption>`, ...),
+/// the element's content for roles that allow naming from content, and
+/// finally the `title` and `placeholder` attributes.
+///
+/// [accname 1.2]: https://www.w3.org/TR/accname-1.2/
+pub fn accessible_name(element: &Element) -> String {
+	let mut context = Context::default();
+	normalize(&context.text_alternative(element, Traversal::Root))
+}
+
+/// Compute the accessible description of an element, the extra text a screen
+/// reader announces after its name. This comes from `aria-describedby`,
+/// `aria-description`, or the `title` attribute when it wasn't used for the
+/// name.
+pub fn accessible_description(element: &Element) -> String {
+	if let Some(ids) = element.get_attribute("aria-describedby") {
+		let mut context = Context::default();
+		let description = normalize(&context.referenced_text(&ids));
+		if !description.is_empty() {
+			return description;
+		}
+	}
+
+	if let Some(description) = element.get_attribute("aria-description") {
+		let description = normalize(&description);
+		if !description.is_empty() {
+			return description;
+		}
+	}
+
+	element
+		.get_attribute("title")
+		.map(|title| normalize(&title))
+		.filter(|title| !title.is_empty() && *title != accessible_name(element))
+		.unwrap_or_default()
+}
+
+/// Roles whose accessible name can be computed from their content.
+const NAME_FROM_CONTENT_ROLES: &[&str] = &[
+	"button",
+	"cell",
+	"checkbox",
+	"columnheader",
+	"gridcell",
+	"heading",
+	"link",
+	"menuitem",
+	"menuitemcheckbox",
+	"menuitemradio",
+	"option",
+	"radio",
+	"row",
+	"rowheader",
+	"switch",
+	"tab",
+	"term",
+	"tooltip",
+	"treeitem",
+];
+
+/// Why an element is being visited.
+#[derive(Clone, Copy, PartialEq)]
+enum Traversal {
+	/// The element whose name is being computed.
+	Root,
+	/// An element referenced by `aria-labelledby`, `aria-describedby` or a
+	/// `<label>` of the root.
+	Referenced,
+	/// A descendant visited while computing a name from content.
+	Content,
+}
+
+#[derive(Default)]
+struct Context {
+	visited: Vec<Element>,
+}
+
+impl Context {
+	fn text_alternative(&mut self, element: &Element, traversal: Traversal) -> String {
+		if self.visited.contains(element) {
+			return String::new();
+		}
+		self.visited.push(element.clone());
+
+		// 2A: hidden descendants don't contribute to the name.
+		if traversal == Traversal::Content && is_inaccessible(element) {
+			return String::new();
+		}
+
+		// 2B: `aria-labelledby`, unless already following a reference.
+		if traversal != Traversal::Referenced {
+			if let Some(ids) = element.get_attribute("aria-labelledby") {
+				let name = self.referenced_text(&ids);
+				if !name.trim().is_empty() {
+					return name;
+				}
+			}
+		}
+
+		let role = element_role(element);
+		let role = role.as_deref().unwrap_or_default();
+
+		// 2C and 2E: an embedded control contributes its value rather than its
+		// label when it is part of another element's name.
+		if traversal != Traversal::Root && is_embedded_control(role) {
+			return control_value(element, role);
+		}
+
+		if let Some(label) = element
+			.get_attribute("aria-label")
+			.filter(|label| !label.trim().is_empty())
+		{
+			return label;
+		}
+
+		// 2D: the host language label.
+		if role != "presentation" && role != "none" {
+			let name = self.native_text(element);
+			if !name.trim().is_empty() {
+				return name;
+			}
+		}
+
+		// 2F: the name from content.
+		if traversal != Traversal::Root || NAME_FROM_CONTENT_ROLES.contains(&role) {
+			let name = self.content_text(element);
+			if !name.trim().is_empty() {
+				return name;
+			}
+		}
+
+		// 2I: the tooltip, with the placeholder as a last resort for text fields.
+		for attribute in ["title", "placeholder"] {
+			if let Some(value) = element
+				.get_attribute(attribute)
+				.filter(|value| !value.trim().is_empty())
+			{
+				return value;
+			}
+		}
+
+		String::new()
+	}
+
+	fn referenced_text(&mut self, ids: &str) -> String {
+		let document = document();
+		ids.split_whitespace()
+			.filter_map(|id| document.get_element_by_id(id))
+			.map(|element| self.text_alternative(&element, Traversal::Referenced))
+			.collect::<Vec<_>>()
+			.join(" ")
+	}
+
+	fn content_text(&mut self, element: &Element) -> String {
+		let window = window();
+		let children = element.child_nodes();
+		let mut text = String::new();
+		for i in 0..children.length() {
+			let child = children.item(i).unwrap();
+			if child.node_type() == Node::TEXT_NODE {
+				text.push_str(&child.text_content().unwrap_or_default());
+			} else if let Some(child) = child.dyn_ref::<Element>() {
+				let child_text = self.text_alternative(child, Traversal::Content);
+				let is_inline = window
+					.get_computed_style(child)
+					.ok()
+					.flatten()
+					.and_then(|style| style.get_property_value("display").ok())
+					.is_some_and(|display| display == "inline");
+				if is_inline {
+					text.push_str(&child_text);
+				} else {
+					text.push_str(&format!(" {child_text} "));
+				}
+			}
+		}
+		text
+	}
+
+	fn native_text(&mut self, element: &Element) -> String {
+		let tag_name = element.tag_name().to_lowercase();
+		let input_type = element
+			.get_attribute("type")
+			.unwrap_or_default()
+			.to_lowercase();
+
+		if tag_name == "input" {
+			match input_type.as_str() {
+				"button" | "submit" | "reset" => {
+					let default = match input_type.as_str() {
+						"submit" => "Submit",
+						"reset" => "Reset",
+						_ => "",
+					};
+					return element
+						.get_attribute("value")
+						.unwrap_or_else(|| default.to_string());
+				}
+				"image" => {
+					return element
+						.get_attribute("alt")
+						.or_else(|| element.get_attribute("value"))
+						.unwrap_or_else(|| "Submit Query".to_string());
+				}
+				_ => {}
+			}
+		}
+
+		match tag_name.as_str() {
+			"button" | "input" | "meter" | "output" | "progress" | "select" | "textarea" => {
+				self.label_text(element)
+			}
+			"img" | "area" => element.get_attribute("alt").unwrap_or_default(),
+			"fieldset" => self.child_text(element, "legend"),
+			"figure" => self.child_text(element, "figcaption"),
+			"table" => self.child_text(element, "caption"),
+			"svg" => self.child_text(element, "title"),
+			_ => String::new(),
+		}
+	}
+
+	/// The text of every `<label>` associated with a form control, either
+	/// through its `for` attribute or by wrapping the control.
+	fn label_text(&mut self, element: &Element) -> String {
+		let labels = document().query_selector_all("label").unwrap();
+		let mut text = Vec::new();
+		for i in 0..labels.length() {
+			let label = labels.item(i).unwrap();
+			if label
+				.dyn_ref::<web_sys::HtmlLabelElement>()
+				.and_then(web_sys::HtmlLabelElement::control)
+				.is_some_and(|control| control.is_same_node(Some(element)))
+			{
+				text.push(self.text_alternative(label.unchecked_ref(), Traversal::Referenced));
+			}
+		}
+		text.join(" ")
+	}
+
+	fn child_text(&mut self, element: &Element, tag_name: &str) -> String {
+		let children = element.children();
+		for i in 0..children.length() {
+			let child = children.item(i).unwrap();
+			if child.tag_name().eq_ignore_ascii_case(tag_name) {
+				return self.text_alternative(&child, Traversal::Referenced);
+			}
+		}
+		String::new()
+	}
+}
+
+fn is_embedded_control(role: &str) -> bool {
+	matches!(
+		role,
+		"combobox"
+			| "listbox"
+			| "meter" | "progressbar"
+			| "scrollbar"
+			| "slider"
+			| "spinbutton"
+			| "textbox"
+	)
+}
+
+fn control_value(element: &Element, role: &str) -> String {
+	match role {
+		"textbox" => {
+			if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+				input.value()
+			} else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+				textarea.value()
+			} else {
+				element.text_content().unwrap_or_default()
+			}
+		}
+		"combobox" | "listbox" => {
+			if let Some(select) = element.dyn_ref::<web_sys::HtmlSelectElement>() {
+				let options = select.selected_options();
+				(0..options.length())
+					.filter_map(|i| options.item(i))
+					.map(|option| option.text_content().unwrap_or_default())
+					.collect::<Vec<_>>()
+					.join(" ")
+			} else if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+				input.value()
+			} else {
+				element
+					.query_selector("[aria-selected='true']")
+					.ok()
+					.flatten()
+					.and_then(|option| option.text_content())
+					.unwrap_or_default()
+			}
+		}
+		_ => {
+			element
+				.get_attribute("aria-valuetext")
+				.or_else(|| element.get_attribute("aria-valuenow"))
+				.or_else(|| {
+					element
+						.dyn_ref::<web_sys::HtmlInputElement>()
+						.map(web_sys::HtmlInputElement::value)
+				})
+				.unwrap_or_default()
+		}
+	}
+}
+
+/// Trim and collapse all whitespace into single spaces.
+fn normalize(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
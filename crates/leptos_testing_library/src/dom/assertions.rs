@@ -0,0 +1,366 @@
+//! Assertions about the state of an element, in the style of `jest-dom`.
+//!
+//! Start with [`expect`] and call one of the `to_X` matchers on the result.
+//! Every matcher can be negated with [`Expect::not`]. When an assertion
+//! fails it panics with a message that includes the HTML of the element.
+//!
+//! ```rust
+//! # #[cfg(target_arch = "wasm32")]
+//! # mod hidden_example {
+//! use leptos_testing_library::prelude::*;
+//!
+//! fn check_save_button(render: &impl DomQuery) {
+//! 	let button = render.get_by_role("button").unwrap();
+//! 	expect(&button).to_be_visible();
+//! 	expect(&button).not().to_be_disabled();
+//! 	expect(&button).to_have_class("primary");
+//! 	expect(&button).to_have_accessible_name("Save");
+//! }
+//! # }
+//! ```
+
+use leptos::prelude::document;
+use leptos::prelude::window;
+
+use super::*;
+
+/// Start an assertion about `element`. See the [module docs](self).
+pub fn expect(element: &Element) -> Expect<'_> {
+	Expect {
+		element,
+		negated: false,
+	}
+}
+
+/// The matchers for an element, created by [`expect`].
+#[derive(Clone, Copy, Debug)]
+#[must_use = "an expectation does nothing until a matcher is called on it"]
+pub struct Expect<'a> {
+	element: &'a Element,
+	negated: bool,
+}
+
+impl Expect<'_> {
+	/// Negate the next matcher, so it passes when the element doesn't match.
+	#[allow(clippy::should_implement_trait)]
+	pub fn not(self) -> Self {
+		Self {
+			negated: !self.negated,
+			..self
+		}
+	}
+
+	/// Assert that the element is in the document and isn't hidden, neither
+	/// by itself nor by one of its ancestors. Elements are hidden by the
+	/// `hidden` attribute, `display: none`, `visibility: hidden` and
+	/// `opacity: 0`.
+	pub fn to_be_visible(self) {
+		let hidden_by = if self.element.is_connected() {
+			hidden_by(self.element)
+		} else {
+			Some("it isn't in the document".into())
+		};
+		let received = hidden_by.as_deref().map_or_else(
+			|| "the element is visible".into(),
+			|reason| format!("the element is hidden, {reason}"),
+		);
+		self.assert(hidden_by.is_none(), "to_be_visible()", &received);
+	}
+
+	/// Assert that the element is disabled. This is the case for form
+	/// controls with the `disabled` attribute, controls in a disabled
+	/// `<fieldset>`, and elements with `aria-disabled="true"`.
+	pub fn to_be_disabled(self) {
+		let disabled = is_disabled(self.element);
+		self.assert(disabled, "to_be_disabled()", describe_disabled(disabled));
+	}
+
+	/// Assert that the element isn't disabled. See [`Expect::to_be_disabled`].
+	pub fn to_be_enabled(self) {
+		let disabled = is_disabled(self.element);
+		self.assert(!disabled, "to_be_enabled()", describe_disabled(disabled));
+	}
+
+	/// Assert that a checkbox or radio button is checked, either natively or,
+	/// for elements with a `checkbox`, `radio` or `switch` role, with
+	/// `aria-checked="true"`.
+	pub fn to_be_checked(self) {
+		let checked = match self.element.dyn_ref::<web_sys::HtmlInputElement>() {
+			Some(input) if matches!(input.type_().as_str(), "checkbox" | "radio") => {
+				Some(input.checked())
+			}
+			_ => {
+				let checkable = element_role(self.element)
+					.is_some_and(|role| matches!(role.as_str(), "checkbox" | "radio" | "switch"));
+				checkable
+					.then(|| self.element.get_attribute("aria-checked").as_deref() == Some("true"))
+			}
+		};
+		let Some(checked) = checked else {
+			panic!(
+				"{}\n\nOnly checkboxes, radio buttons and elements with a checkbox, radio or \
+				 switch role can be checked.\n\n{}",
+				self.header("to_be_checked()"),
+				pretty_html(self.element, get_config().max_dom_length)
+			);
+		};
+
+		let received = if checked {
+			"the element is checked"
+		} else {
+			"the element isn't checked"
+		};
+		self.assert(checked, "to_be_checked()", received);
+	}
+
+	/// Assert that the element has the attribute `name`, whatever its value.
+	pub fn to_have_attribute(self, name: &str) {
+		let value = self.element.get_attribute(name);
+		self.assert(
+			value.is_some(),
+			&format!("to_have_attribute({name:?})"),
+			&describe_attribute(name, value.as_deref()),
+		);
+	}
+
+	/// Assert that the element has the attribute `name` set to `value`.
+	pub fn to_have_attribute_value(self, name: &str, value: &str) {
+		let received = self.element.get_attribute(name);
+		self.assert(
+			received.as_deref() == Some(value),
+			&format!("to_have_attribute_value({name:?}, {value:?})"),
+			&describe_attribute(name, received.as_deref()),
+		);
+	}
+
+	/// Assert that the element has every class in the space separated
+	/// `classes`, in any order.
+	pub fn to_have_class(self, classes: &str) {
+		let class_name = self.element.class_name();
+		let has_classes = classes
+			.split_whitespace()
+			.all(|class| class_name.split_whitespace().any(|other| other == class));
+		self.assert(
+			has_classes,
+			&format!("to_have_class({classes:?})"),
+			&format!("the element has the classes {class_name:?}"),
+		);
+	}
+
+	/// Assert that the computed style of the element has every declaration in
+	/// `css`, i.e `"display: flex; color: red"`. Values are compared after
+	/// they're computed, so `red` matches `rgb(255, 0, 0)`.
+	pub fn to_have_style(self, css: &str) {
+		let expected = computed_declarations(css);
+		let style = window()
+			.get_computed_style(self.element)
+			.ok()
+			.flatten()
+			.expect("the element to have a computed style");
+		let mismatches = expected
+			.iter()
+			.filter_map(|(property, value)| {
+				let received = style.get_property_value(property).unwrap_or_default();
+				(&received != value).then(|| format!("{property}: {received};"))
+			})
+			.collect::<Vec<_>>();
+		let received = if mismatches.is_empty() {
+			"every declaration matches".into()
+		} else {
+			format!("the element has the style {}", mismatches.join(" "))
+		};
+		self.assert(
+			mismatches.is_empty(),
+			&format!("to_have_style({css:?})"),
+			&received,
+		);
+	}
+
+	/// Assert that the value of an `<input>`, `<textarea>` or `<select>` is
+	/// `value`.
+	pub fn to_have_value(self, value: &str) {
+		let received = form_value(self.element).unwrap_or_else(|| {
+			panic!(
+				"{}\n\nOnly inputs, textareas and selects have a value.\n\n{}",
+				self.header(&format!("to_have_value({value:?})")),
+				pretty_html(self.element, get_config().max_dom_length)
+			)
+		});
+		self.assert(
+			received == value,
+			&format!("to_have_value({value:?})"),
+			&format!("the element has the value {received:?}"),
+		);
+	}
+
+	/// Assert that the text content of the element matches `text`. See
+	/// [`TextMatch`].
+	pub fn to_have_text_content<M: Into<TextMatch>>(self, text: M) {
+		let text = text.into();
+		let received = self.element.text_content().unwrap_or_default();
+		self.assert(
+			text.matches(&received, self.element),
+			&format!("to_have_text_content({text})"),
+			&format!(
+				"the element has the text {:?}",
+				default_normalizer(&received)
+			),
+		);
+	}
+
+	/// Assert that the element is the focused element of the document.
+	pub fn to_have_focus(self) {
+		let active_element = document().active_element();
+		let received = match &active_element {
+			Some(active) if active == self.element => "the element has focus".into(),
+			Some(active) => format!("the focused element is {}", active.outer_html()),
+			None => "no element has focus".into(),
+		};
+		self.assert(
+			active_element.as_ref() == Some(self.element),
+			"to_have_focus()",
+			&received,
+		);
+	}
+
+	/// Assert that the element is in the document.
+	pub fn to_be_in_the_document(self) {
+		let connected = self.element.is_connected();
+		let received = if connected {
+			"the element is in the document"
+		} else {
+			"the element isn't in the document"
+		};
+		self.assert(connected, "to_be_in_the_document()", received);
+	}
+
+	/// Assert that the element has no content. Comments, like the markers
+	/// Leptos inserts, are ignored.
+	pub fn to_be_empty_dom_element(self) {
+		let child_nodes = self.element.child_nodes();
+		let empty = (0..child_nodes.length())
+			.filter_map(|index| child_nodes.item(index))
+			.all(|node| node.node_type() == Node::COMMENT_NODE);
+		let received = if empty {
+			"the element is empty"
+		} else {
+			"the element has content"
+		};
+		self.assert(empty, "to_be_empty_dom_element()", received);
+	}
+
+	/// Assert that the accessible name of the element matches `name`. See
+	/// [`accessible_name`].
+	pub fn to_have_accessible_name<M: Into<TextMatch>>(self, name: M) {
+		let name = name.into();
+		let received = accessible_name(self.element);
+		self.assert(
+			name.matches(&received, self.element),
+			&format!("to_have_accessible_name({name})"),
+			&format!("the element has the accessible name {received:?}"),
+		);
+	}
+
+	fn header(self, matcher: &str) -> String {
+		let not = if self.negated { ".not()" } else { "" };
+		format!("expect(element){not}.{matcher}")
+	}
+
+	/// Panic unless `pass` is `true`, or `false` when negated.
+	fn assert(self, pass: bool, matcher: &str, received: &str) {
+		assert!(
+			pass != self.negated,
+			"{}\n\nReceived: {received}\n\n{}",
+			self.header(matcher),
+			pretty_html(self.element, get_config().max_dom_length)
+		);
+	}
+}
+
+fn is_disabled(element: &Element) -> bool {
+	element.matches(":disabled").unwrap_or(false)
+		|| element.get_attribute("aria-disabled").as_deref() == Some("true")
+}
+
+fn describe_disabled(disabled: bool) -> &'static str {
+	if disabled {
+		"the element is disabled"
+	} else {
+		"the element is enabled"
+	}
+}
+
+fn describe_attribute(name: &str, value: Option<&str>) -> String {
+	match value {
+		Some(value) => format!("the element has {name}={value:?}"),
+		None => format!("the element has no {name} attribute"),
+	}
+}
+
+/// Why `element` is hidden, if it or one of its ancestors is.
+fn hidden_by(element: &Element) -> Option<String> {
+	let window = window();
+	let mut current = Some(element.clone());
+	while let Some(element) = current {
+		if element.has_attribute("hidden") {
+			return Some(format!("{} has the hidden attribute", tag_name(&element)));
+		}
+		if let Some(style) = window.get_computed_style(&element).ok().flatten() {
+			let property = |name| style.get_property_value(name).unwrap_or_default();
+			for (name, hidden) in [
+				("display", "none"),
+				("visibility", "hidden"),
+				("visibility", "collapse"),
+				("opacity", "0"),
+			] {
+				if property(name) == hidden {
+					return Some(format!("{} has {name}: {hidden}", tag_name(&element)));
+				}
+			}
+		}
+		current = element.parent_element();
+	}
+
+	None
+}
+
+fn tag_name(element: &Element) -> String {
+	format!("<{}>", element.tag_name().to_lowercase())
+}
+
+/// Compute the declarations in `css` by applying them to a temporary
+/// element, so they can be compared with the computed style of another one.
+fn computed_declarations(css: &str) -> Vec<(String, String)> {
+	let body = document().body().expect("the document to have a body");
+	let probe = document().create_element("div").unwrap();
+	probe.set_attribute("style", css).unwrap();
+	body.append_child(&probe).unwrap();
+
+	let declared = probe.unchecked_ref::<HtmlElement>().style();
+	let computed = window().get_computed_style(&probe).ok().flatten();
+	let declarations = (0..declared.length())
+		.map(|index| declared.item(index))
+		.map(|property| {
+			let value = computed
+				.as_ref()
+				.and_then(|style| style.get_property_value(&property).ok())
+				.unwrap_or_else(|| declared.get_property_value(&property).unwrap_or_default());
+			(property, value)
+		})
+		.collect();
+
+	probe.remove();
+	declarations
+}
+
+fn form_value(element: &Element) -> Option<String> {
+	if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+		Some(input.value())
+	} else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+		Some(textarea.value())
+	} else {
+		element
+			.dyn_ref::<web_sys::HtmlSelectElement>()
+			.map(web_sys::HtmlSelectElement::value)
+	}
+}
@@ -0,0 +1,80 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use super::*;
+
+/// Global configuration shared by every query in this crate. Use
+/// [`configure`] to change it, usually once at the top of a test.
+#[derive(Clone)]
+pub struct Config {
+	/// The attribute used by the `get_by_test_id` series of queries. Defaults
+	/// to `data-testid`.
+	pub test_id_attribute: String,
+	/// How long the `find_by_X` series of queries keep retrying before
+	/// giving up with a `Timeout` error. Defaults to one second.
+	pub async_util_timeout: Duration,
+	/// The longest the `find_by_X` series waits between two attempts when no
+	/// DOM mutation happens in the meantime. Defaults to 50 milliseconds.
+	pub async_util_interval: Duration,
+	/// Normalizes text before it is matched by a [`TextMatch`]. Defaults to
+	/// [`default_normalizer`], which trims and collapses whitespace.
+	pub normalizer: Normalizer,
+	/// The most characters of the DOM printed in `NotFound` and
+	/// `MoreThanOne` errors before it is truncated. Defaults to 7000.
+	pub max_dom_length: usize,
+	/// Log a warning when a `get_by_X` query finds an element that a more
+	/// accessible query would find too, naming that query. See
+	/// [`TestElement::suggested_query`]. Defaults to `false`.
+	pub warn_on_lower_priority_queries: bool,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			test_id_attribute: "data-testid".into(),
+			async_util_timeout: Duration::from_millis(1000),
+			async_util_interval: Duration::from_millis(50),
+			normalizer: Rc::new(default_normalizer),
+			max_dom_length: 7000,
+			warn_on_lower_priority_queries: false,
+		}
+	}
+}
+
+impl fmt::Debug for Config {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Config")
+			.field("test_id_attribute", &self.test_id_attribute)
+			.field("async_util_timeout", &self.async_util_timeout)
+			.field("async_util_interval", &self.async_util_interval)
+			.field("max_dom_length", &self.max_dom_length)
+			.field(
+				"warn_on_lower_priority_queries",
+				&self.warn_on_lower_priority_queries,
+			)
+			.finish_non_exhaustive()
+	}
+}
+
+thread_local! {
+	static CONFIG: RefCell<Config> = RefCell::new(Config::default());
+}
+
+/// Update the global configuration.
+///
+/// ```rust
+/// use leptos_testing_library::prelude::*;
+///
+/// configure(|config| config.test_id_attribute = "data-qa".into());
+/// assert_eq!(get_config().test_id_attribute, "data-qa");
+/// ```
+pub fn configure<F: FnOnce(&mut Config)>(f: F) {
+	CONFIG.with_borrow_mut(f);
+}
+
+/// Get a copy of the current global configuration.
+pub fn get_config() -> Config {
+	CONFIG.with_borrow(Clone::clone)
+}
@@ -27,50 +27,97 @@ use super::*;
 /// And now you can use `DomQuery` on `ServerFrontEndStruct`.
 pub trait HoldsElement {
 	fn element(&self) -> ElementWrapper;
+
+	/// Print the element to the console, formatted with [`pretty_dom`] and
+	/// the default [`PrettyOptions`].
+	fn debug(&self) {
+		leptos::logging::log!(
+			"{}",
+			pretty_dom(self.element().0, &PrettyOptions::default())
+		);
+	}
 }
 
 /// `DomQuery` is a trait that is implement on a data structure that holds onto
 /// the document for the test. The `get_by_X` series tries to get exactly one
 /// element given the input by method of X They return an `Error::NotFound`
 /// result if 0, or a `MoreThanOne` error if more than one. The `get_all_by_X`
-/// series returns a list of 0 or more items given the input by method of ˙˙˙
+/// series returns a list of 0 or more items given the input by method of X.
+///
+/// The `query_by_X` series is built on top of `get_by_X` and is meant for
+/// asserting that an element is *not* rendered. It returns `Ok(None)` if 0
+/// elements match, and still returns a `MoreThanOne` error if more than one
+/// does. The `query_all_by_X` series is the same as `get_all_by_X`.
+///
+/// The async `find_by_X` and `find_all_by_X` series keep retrying their
+/// `get_by_X` counterparts whenever the DOM changes, until they succeed or the
+/// timeout from [`Config::async_util_timeout`] elapses. On timeout they return
+/// a `Timeout` error wrapping the last error seen.
+///
+/// Every query accepts anything that converts into a [`TextMatch`]: a string
+/// for an exact match, a [`regex::Regex`], or a closure over the text and the
+/// element. The `_contains` variants are shorthand for
+/// [`TextMatch::contains`].
+// The returned futures are only ever polled on the single threaded wasm
+// executor, so there is no need for a `Send` bound.
+#[allow(async_fn_in_trait)]
 pub trait DomQuery {
-	/// Get the element whose inner text matches this method's input, exactly.
+	/// Get the element whose own text matches this method's input, exactly.
 	/// See `get_by_text_contains` for a non-exact matching method.
-	fn get_by_text<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError>;
-	/// Get all elements whose inner text matches this method's input, exactly.
+	///
+	/// The own text of an element is the text of its direct child text nodes,
+	/// like in DOM Testing Library. Text split by nested markup, like
+	/// `<p>Hello <b>world</b></p>`, is not matched as a whole: the `<p>` has
+	/// the text "Hello " and the `<b>` has "world". Use a [`TextMatch`]
+	/// predicate over the element to match its whole text content instead.
+	fn get_by_text<M: Into<TextMatch>>(&self, text: M) -> Result<TestElement, TestingLibraryError>;
+	/// Get all elements whose own text matches this method's input, exactly.
 	/// `Seeget_by_text_contains` for a non-exact matching method.
-	fn get_all_by_text<S: AsRef<str>>(&self, text: S) -> Vec<TestElement>;
-	/// Get an element whose inner text contains the text content, i.e "abc"
+	fn get_all_by_text<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement>;
+	/// Get an element whose own text contains the text content, i.e "abc"
 	/// contains "a". `Seeget_by_text` for an exact matcher.
 	fn get_by_text_contains<S: AsRef<str>>(
 		&self,
 		text: S,
-	) -> Result<TestElement, TestingLibraryError>;
-	/// Get a list of elements whose inner text contains the text content, i.e
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_text(TextMatch::contains(text))
+	}
+	/// Get a list of elements whose own text contains the text content, i.e
 	/// "abc" contains "a". `Seeget_by_text` for an exact matcher.
-	fn get_all_by_text_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement>;
+	fn get_all_by_text_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+		self.get_all_by_text(TextMatch::contains(text))
+	}
 	/// Get an element by it's id, matches exactly. See `get_by_id_contains` for
 	/// non-exact matching.
-	fn get_by_id<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError>;
+	fn get_by_id<M: Into<TextMatch>>(&self, id: M) -> Result<TestElement, TestingLibraryError>;
 	/// Get all elements by their id, matches exactly.
-	fn get_all_by_id<S: AsRef<str>>(&self, id: S) -> Vec<TestElement>;
+	fn get_all_by_id<M: Into<TextMatch>>(&self, id: M) -> Vec<TestElement>;
 	/// Get an element whose id contains the text string, see `get_by_id` for
 	/// exact id matching.
-	fn get_by_id_contains<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError>;
+	fn get_by_id_contains<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_id(TextMatch::contains(id))
+	}
 	/// Get a list of elements whose id contains the text string, see
 	/// `get_by_id` for exact id matching.
-	fn get_all_by_id_contains<S: AsRef<str>>(&self, id: S) -> Vec<TestElement>;
+	fn get_all_by_id_contains<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
+		self.get_all_by_id(TextMatch::contains(id))
+	}
 	/// Get the element that is pointed to by a label whose text is the input of
 	/// the method. i.e <label for="field">Btn</label><input id="field"/>
 	/// With input of "field" would return the input whose id is field.
 	/// If you want to find the label element itself, see `get_by_text`
-	fn get_by_label<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError>;
+	///
+	/// Labels can also wrap their control, i.e <label>Btn <input/></label>,
+	/// and elements can be labelled with `aria-labelledby` or `aria-label`.
+	/// Returns a `LabelWithoutControl` error if a matching `<label>` isn't
+	/// associated with any element.
+	fn get_by_label<M: Into<TextMatch>>(&self, text: M)
+	-> Result<TestElement, TestingLibraryError>;
 	/// Get a list of  elements that are pointed to by a label whose text is the
 	/// input of the method. i.e <label for="field">Btn</label><input
 	/// id="field"/> With input of "field" would return the input whose id is
 	/// field. If you want to find the label element itself, see `get_by_text`
-	fn get_all_by_label<S: AsRef<str>>(&self, text: S) -> Vec<TestElement>;
+	fn get_all_by_label<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement>;
 	/// Get the element that is pointed to by a label whose text is the input of
 	/// the method. i.e <label for="field">Btn</label><input id="field"/>
 	/// With input of "field" would return the input whose id is field.
@@ -78,148 +125,894 @@ pub trait DomQuery {
 	fn get_by_label_contains<S: AsRef<str>>(
 		&self,
 		text: S,
-	) -> Result<TestElement, TestingLibraryError>;
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_label(TextMatch::contains(text))
+	}
 	/// Get a list of  elements that are pointed to by a label whose text is the
 	/// input of the method. i.e <label for="field">Btn</label><input
 	/// id="field"/> With input of "field" would return the input whose id is
 	/// field. If you want to find the label element itself, see `get_by_text`
-	fn get_all_by_label_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement>;
+	fn get_all_by_label_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+		self.get_all_by_label(TextMatch::contains(text))
+	}
 	/// Get elements whose display value is the exact match of this methods
 	/// input. The elements that this method will find are: input, textarea,
 	/// and select. This method will not match against items with non-display
 	/// value attributes, i.e option, progress, li etc.
-	fn get_by_display_value<S: AsRef<str>>(
+	fn get_by_display_value<M: Into<TextMatch>>(
 		&self,
-		value: S,
+		value: M,
 	) -> Result<TestElement, TestingLibraryError>;
 	/// Get elements whose display value is the exact match of this methods
 	/// input. The elements that this method will find are: input, textarea,
 	/// and select. This method will not match against items with non-display
 	/// value attributes, i.e option, progress, li etc.
-	fn get_all_by_display_value<S: AsRef<str>>(&self, value: S) -> Vec<TestElement>;
-	/// Get an element matching ARIA role.
-	fn get_by_role<S: AsRef<str>>(&self, role: S) -> Result<TestElement, TestingLibraryError>;
+	fn get_all_by_display_value<M: Into<TextMatch>>(&self, value: M) -> Vec<TestElement>;
+	/// Get an element matching ARIA role. Both explicit `role` attributes and
+	/// implicit roles are matched, i.e `<button>` has the role `button`. See
+	/// [`element_role`](crate::dom::element_role). Elements that are hidden
+	/// from the accessibility tree are skipped, see `get_by_role_with` to
+	/// include them.
+	fn get_by_role<M: Into<TextMatch>>(&self, role: M) -> Result<TestElement, TestingLibraryError>;
 	/// Get a list of elements matching AIRA role.
-	fn get_all_by_role<S: AsRef<str>>(&self, role: S) -> Vec<TestElement>;
+	fn get_all_by_role<M: Into<TextMatch>>(&self, role: M) -> Vec<TestElement>;
+	/// Get an element matching ARIA role that also passes the filters in
+	/// `options`, i.e `get_by_role_with("heading",
+	/// ByRoleOptions::default().level(2))`.
+	fn get_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Result<TestElement, TestingLibraryError>;
+	/// Get a list of elements matching ARIA role that also pass the filters in
+	/// `options`.
+	fn get_all_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Vec<TestElement>;
 	/// Get by placeholder text, checks textarea and input only. As those are
 	/// the only applicable elements with placeholders. Matches exactly.
-	fn get_by_placeholder<S: AsRef<str>>(
+	fn get_by_placeholder<M: Into<TextMatch>>(
 		&self,
-		placeholder: S,
+		placeholder: M,
 	) -> Result<TestElement, TestingLibraryError>;
 	/// Get a list of elements by placeholder text, checks textarea and input
 	/// only. As those are the only applicable elements with placeholders.
 	/// Matches exactly.
-	fn get_all_by_placeholder<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement>;
+	fn get_all_by_placeholder<M: Into<TextMatch>>(&self, placeholder: M) -> Vec<TestElement>;
 	/// Get by placeholder text, checks textarea and input only. As those are
 	/// the only applicable elements with placeholders. Checks if placeholder
 	/// text contains method input.
 	fn get_by_placeholder_contains<S: AsRef<str>>(
 		&self,
 		placeholder: S,
-	) -> Result<TestElement, TestingLibraryError>;
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_placeholder(TextMatch::contains(placeholder))
+	}
 	/// Get a list of elements by placeholder text, checks textarea and input
 	/// only. As those are the only applicable elements with placeholders.
 	/// Checks if placeholder text contains method input.
-	fn get_all_by_placeholder_contains<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement>;
+	fn get_all_by_placeholder_contains<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
+		self.get_all_by_placeholder(TextMatch::contains(placeholder))
+	}
+	/// Get the element whose test id attribute matches this method's input,
+	/// exactly. The attribute defaults to `data-testid` and can be changed
+	/// with [`configure`](crate::dom::configure).
+	fn get_by_test_id<M: Into<TextMatch>>(
+		&self,
+		test_id: M,
+	) -> Result<TestElement, TestingLibraryError>;
+	/// Get all elements whose test id attribute matches this method's input,
+	/// exactly. See `get_all_by_test_id_contains` for a non-exact matching
+	/// method.
+	fn get_all_by_test_id<M: Into<TextMatch>>(&self, test_id: M) -> Vec<TestElement>;
+	/// Get the element whose test id attribute contains this method's input.
+	/// See `get_by_test_id` for an exact matcher.
+	fn get_by_test_id_contains<S: AsRef<str>>(
+		&self,
+		test_id: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_test_id(TextMatch::contains(test_id))
+	}
+	/// Get a list of elements whose test id attribute contains this method's
+	/// input. See `get_all_by_test_id` for an exact matcher.
+	fn get_all_by_test_id_contains<S: AsRef<str>>(&self, test_id: S) -> Vec<TestElement> {
+		self.get_all_by_test_id(TextMatch::contains(test_id))
+	}
+	/// Get the element whose `alt` attribute matches this method's input,
+	/// exactly. Only `<img>`, `<input type="image">` and `<area>` elements are
+	/// checked, as those are the elements that support alt text.
+	fn get_by_alt_text<M: Into<TextMatch>>(
+		&self,
+		alt: M,
+	) -> Result<TestElement, TestingLibraryError>;
+	/// Get all elements whose `alt` attribute matches this method's input,
+	/// exactly. See `get_by_alt_text`.
+	fn get_all_by_alt_text<M: Into<TextMatch>>(&self, alt: M) -> Vec<TestElement>;
+	/// Get the element whose alt text contains this method's input. See
+	/// `get_by_alt_text` for an exact matcher.
+	fn get_by_alt_text_contains<S: AsRef<str>>(
+		&self,
+		alt: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_alt_text(TextMatch::contains(alt))
+	}
+	/// Get all elements whose alt text contains this method's input. See
+	/// `get_all_by_alt_text` for an exact matcher.
+	fn get_all_by_alt_text_contains<S: AsRef<str>>(&self, alt: S) -> Vec<TestElement> {
+		self.get_all_by_alt_text(TextMatch::contains(alt))
+	}
+	/// Get the element whose `title` attribute matches this method's input,
+	/// exactly. Inline `<svg>` elements also match on the text of their
+	/// `<title>` child, in which case the closest HTML element around the
+	/// `<svg>`, i.e the icon's `<span>` or `<button>`, is returned. SVG
+	/// elements with a `title` attribute are skipped, since they aren't HTML
+	/// elements.
+	fn get_by_title<M: Into<TextMatch>>(
+		&self,
+		title: M,
+	) -> Result<TestElement, TestingLibraryError>;
+	/// Get all elements whose `title` attribute, or SVG `<title>`, matches this
+	/// method's input, exactly. See `get_by_title`.
+	fn get_all_by_title<M: Into<TextMatch>>(&self, title: M) -> Vec<TestElement>;
+	/// Get the element whose title contains this method's input. See
+	/// `get_by_title` for an exact matcher.
+	fn get_by_title_contains<S: AsRef<str>>(
+		&self,
+		title: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.get_by_title(TextMatch::contains(title))
+	}
+	/// Get all elements whose title contains this method's input. See
+	/// `get_all_by_title` for an exact matcher.
+	fn get_all_by_title_contains<S: AsRef<str>>(&self, title: S) -> Vec<TestElement> {
+		self.get_all_by_title(TextMatch::contains(title))
+	}
+	/// Query for the element whose own text matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_text`.
+	fn query_by_text<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let text = text.into();
+		query_one_inner(|| self.get_by_text(text.clone()))
+	}
+	/// Query for all elements whose own text matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_text`.
+	fn query_all_by_text<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
+		self.get_all_by_text(text)
+	}
+	/// Query for the element whose own text contains this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_text_contains`.
+	fn query_by_text_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_text(TextMatch::contains(text))
+	}
+	/// Query for all elements whose own text contains this method's input.
+	/// Returns an empty list when nothing matches. See
+	/// `get_all_by_text_contains`.
+	fn query_all_by_text_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+		self.query_all_by_text(TextMatch::contains(text))
+	}
+	/// Query for the element whose id matches this method's input, exactly.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_id`.
+	fn query_by_id<M: Into<TextMatch>>(
+		&self,
+		id: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let id = id.into();
+		query_one_inner(|| self.get_by_id(id.clone()))
+	}
+	/// Query for all elements whose id matches this method's input, exactly.
+	/// Returns an empty list when nothing matches. See `get_all_by_id`.
+	fn query_all_by_id<M: Into<TextMatch>>(&self, id: M) -> Vec<TestElement> {
+		self.get_all_by_id(id)
+	}
+	/// Query for the element whose id contains this method's input. Returns
+	/// `Ok(None)` when nothing matches and a `MoreThanOne` error when several
+	/// elements match. See `get_by_id_contains`.
+	fn query_by_id_contains<S: AsRef<str>>(
+		&self,
+		id: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_id(TextMatch::contains(id))
+	}
+	/// Query for all elements whose id contains this method's input. Returns an
+	/// empty list when nothing matches. See `get_all_by_id_contains`.
+	fn query_all_by_id_contains<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
+		self.query_all_by_id(TextMatch::contains(id))
+	}
+	/// Query for the element whose label text matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_label`.
+	fn query_by_label<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let text = text.into();
+		query_one_inner(|| self.get_by_label(text.clone()))
+	}
+	/// Query for all elements whose label text matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_label`.
+	fn query_all_by_label<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
+		self.get_all_by_label(text)
+	}
+	/// Query for the element whose label text contains this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_label_contains`.
+	fn query_by_label_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_label(TextMatch::contains(text))
+	}
+	/// Query for all elements whose label text contains this method's input.
+	/// Returns an empty list when nothing matches. See
+	/// `get_all_by_label_contains`.
+	fn query_all_by_label_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+		self.query_all_by_label(TextMatch::contains(text))
+	}
+	/// Query for the element whose display value matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_display_value`.
+	fn query_by_display_value<M: Into<TextMatch>>(
+		&self,
+		value: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let value = value.into();
+		query_one_inner(|| self.get_by_display_value(value.clone()))
+	}
+	/// Query for all elements whose display value matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_display_value`.
+	fn query_all_by_display_value<M: Into<TextMatch>>(&self, value: M) -> Vec<TestElement> {
+		self.get_all_by_display_value(value)
+	}
+	/// Query for the element whose ARIA role matches this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_role`.
+	fn query_by_role<M: Into<TextMatch>>(
+		&self,
+		role: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let role = role.into();
+		query_one_inner(|| self.get_by_role(role.clone()))
+	}
+	/// Query for all elements whose ARIA role matches this method's input.
+	/// Returns an empty list when nothing matches. See `get_all_by_role`.
+	fn query_all_by_role<M: Into<TextMatch>>(&self, role: M) -> Vec<TestElement> {
+		self.get_all_by_role(role)
+	}
+	/// Query for the element whose placeholder matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_placeholder`.
+	fn query_by_placeholder<M: Into<TextMatch>>(
+		&self,
+		placeholder: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let placeholder = placeholder.into();
+		query_one_inner(|| self.get_by_placeholder(placeholder.clone()))
+	}
+	/// Query for all elements whose placeholder matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_placeholder`.
+	fn query_all_by_placeholder<M: Into<TextMatch>>(&self, placeholder: M) -> Vec<TestElement> {
+		self.get_all_by_placeholder(placeholder)
+	}
+	/// Query for the element whose placeholder contains this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_placeholder_contains`.
+	fn query_by_placeholder_contains<S: AsRef<str>>(
+		&self,
+		placeholder: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_placeholder(TextMatch::contains(placeholder))
+	}
+	/// Query for all elements whose placeholder contains this method's input.
+	/// Returns an empty list when nothing matches. See
+	/// `get_all_by_placeholder_contains`.
+	fn query_all_by_placeholder_contains<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
+		self.query_all_by_placeholder(TextMatch::contains(placeholder))
+	}
+	/// Query for the element whose test id attribute matches this method's
+	/// input, exactly. Returns `Ok(None)` when nothing matches and a
+	/// `MoreThanOne` error when several elements match. See `get_by_test_id`.
+	fn query_by_test_id<M: Into<TextMatch>>(
+		&self,
+		test_id: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let test_id = test_id.into();
+		query_one_inner(|| self.get_by_test_id(test_id.clone()))
+	}
+	/// Query for all elements whose test id attribute matches this method's
+	/// input, exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_test_id`.
+	fn query_all_by_test_id<M: Into<TextMatch>>(&self, test_id: M) -> Vec<TestElement> {
+		self.get_all_by_test_id(test_id)
+	}
+	/// Query for the element whose test id attribute contains this method's
+	/// input. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_test_id_contains`.
+	fn query_by_test_id_contains<S: AsRef<str>>(
+		&self,
+		test_id: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_test_id(TextMatch::contains(test_id))
+	}
+	/// Query for all elements whose test id attribute contains this method's
+	/// input. Returns an empty list when nothing matches. See
+	/// `get_all_by_test_id_contains`.
+	fn query_all_by_test_id_contains<S: AsRef<str>>(&self, test_id: S) -> Vec<TestElement> {
+		self.query_all_by_test_id(TextMatch::contains(test_id))
+	}
+	/// Query for the element whose alt text matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_alt_text`.
+	fn query_by_alt_text<M: Into<TextMatch>>(
+		&self,
+		alt: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let alt = alt.into();
+		query_one_inner(|| self.get_by_alt_text(alt.clone()))
+	}
+	/// Query for all elements whose alt text matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_alt_text`.
+	fn query_all_by_alt_text<M: Into<TextMatch>>(&self, alt: M) -> Vec<TestElement> {
+		self.get_all_by_alt_text(alt)
+	}
+	/// Query for the element whose alt text contains this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_alt_text_contains`.
+	fn query_by_alt_text_contains<S: AsRef<str>>(
+		&self,
+		alt: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_alt_text(TextMatch::contains(alt))
+	}
+	/// Query for all elements whose alt text contains this method's input.
+	/// Returns an empty list when nothing matches. See
+	/// `get_all_by_alt_text_contains`.
+	fn query_all_by_alt_text_contains<S: AsRef<str>>(&self, alt: S) -> Vec<TestElement> {
+		self.query_all_by_alt_text(TextMatch::contains(alt))
+	}
+	/// Query for the element whose title matches this method's input,
+	/// exactly. Returns `Ok(None)` when nothing matches and a `MoreThanOne`
+	/// error when several elements match. See `get_by_title`.
+	fn query_by_title<M: Into<TextMatch>>(
+		&self,
+		title: M,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let title = title.into();
+		query_one_inner(|| self.get_by_title(title.clone()))
+	}
+	/// Query for all elements whose title matches this method's input,
+	/// exactly. Returns an empty list when nothing matches. See
+	/// `get_all_by_title`.
+	fn query_all_by_title<M: Into<TextMatch>>(&self, title: M) -> Vec<TestElement> {
+		self.get_all_by_title(title)
+	}
+	/// Query for the element whose title contains this method's input.
+	/// Returns `Ok(None)` when nothing matches and a `MoreThanOne` error when
+	/// several elements match. See `get_by_title_contains`.
+	fn query_by_title_contains<S: AsRef<str>>(
+		&self,
+		title: S,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		self.query_by_title(TextMatch::contains(title))
+	}
+	/// Query for all elements whose title contains this method's input.
+	/// Returns an empty list when nothing matches. See
+	/// `get_all_by_title_contains`.
+	fn query_all_by_title_contains<S: AsRef<str>>(&self, title: S) -> Vec<TestElement> {
+		self.query_all_by_title(TextMatch::contains(title))
+	}
+	/// Wait for the element whose own text matches this method's input,
+	/// exactly. Retries `get_by_text` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_text<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let text = text.into();
+		find_inner(|| self.get_by_text(text.clone())).await
+	}
+	/// Wait for at least one element whose own text matches this method's
+	/// input, exactly. Retries `get_all_by_text` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_text<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let text = text.into();
+		find_all_inner(
+			|| self.get_all_by_text(text.clone()),
+			|| self.get_by_text(text.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose own text contains this method's input.
+	/// Retries `get_by_text_contains` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_text_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_text(TextMatch::contains(text)).await
+	}
+	/// Wait for at least one element whose own text contains this method's
+	/// input. Retries `get_all_by_text_contains` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_text_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_text(TextMatch::contains(text)).await
+	}
+	/// Wait for the element whose id matches this method's input, exactly.
+	/// Retries `get_by_id` until it succeeds or the configured timeout
+	/// elapses.
+	async fn find_by_id<M: Into<TextMatch>>(
+		&self,
+		id: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let id = id.into();
+		find_inner(|| self.get_by_id(id.clone())).await
+	}
+	/// Wait for at least one element whose id matches this method's input,
+	/// exactly. Retries `get_all_by_id` until it finds something or the
+	/// configured timeout elapses.
+	async fn find_all_by_id<M: Into<TextMatch>>(
+		&self,
+		id: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let id = id.into();
+		find_all_inner(
+			|| self.get_all_by_id(id.clone()),
+			|| self.get_by_id(id.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose id contains this method's input. Retries
+	/// `get_by_id_contains` until it succeeds or the configured timeout
+	/// elapses.
+	async fn find_by_id_contains<S: AsRef<str>>(
+		&self,
+		id: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_id(TextMatch::contains(id)).await
+	}
+	/// Wait for at least one element whose id contains this method's input.
+	/// Retries `get_all_by_id_contains` until it finds something or the
+	/// configured timeout elapses.
+	async fn find_all_by_id_contains<S: AsRef<str>>(
+		&self,
+		id: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_id(TextMatch::contains(id)).await
+	}
+	/// Wait for the element whose label text matches this method's input,
+	/// exactly. Retries `get_by_label` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_label<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let text = text.into();
+		find_inner(|| self.get_by_label(text.clone())).await
+	}
+	/// Wait for at least one element whose label text matches this method's
+	/// input, exactly. Retries `get_all_by_label` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_label<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let text = text.into();
+		find_all_inner(
+			|| self.get_all_by_label(text.clone()),
+			|| self.get_by_label(text.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose label text contains this method's input.
+	/// Retries `get_by_label_contains` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_label_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_label(TextMatch::contains(text)).await
+	}
+	/// Wait for at least one element whose label text contains this method's
+	/// input. Retries `get_all_by_label_contains` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_label_contains<S: AsRef<str>>(
+		&self,
+		text: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_label(TextMatch::contains(text)).await
+	}
+	/// Wait for the element whose display value matches this method's input,
+	/// exactly. Retries `get_by_display_value` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_display_value<M: Into<TextMatch>>(
+		&self,
+		value: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let value = value.into();
+		find_inner(|| self.get_by_display_value(value.clone())).await
+	}
+	/// Wait for at least one element whose display value matches this method's
+	/// input, exactly. Retries `get_all_by_display_value` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_display_value<M: Into<TextMatch>>(
+		&self,
+		value: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let value = value.into();
+		find_all_inner(
+			|| self.get_all_by_display_value(value.clone()),
+			|| self.get_by_display_value(value.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose ARIA role matches this method's input.
+	/// Retries `get_by_role` until it succeeds or the configured timeout
+	/// elapses.
+	async fn find_by_role<M: Into<TextMatch>>(
+		&self,
+		role: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let role = role.into();
+		find_inner(|| self.get_by_role(role.clone())).await
+	}
+	/// Wait for at least one element whose ARIA role matches this method's
+	/// input. Retries `get_all_by_role` until it finds something or the
+	/// configured timeout elapses.
+	async fn find_all_by_role<M: Into<TextMatch>>(
+		&self,
+		role: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let role = role.into();
+		find_all_inner(
+			|| self.get_all_by_role(role.clone()),
+			|| self.get_by_role(role.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose placeholder matches this method's input,
+	/// exactly. Retries `get_by_placeholder` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_placeholder<M: Into<TextMatch>>(
+		&self,
+		placeholder: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let placeholder = placeholder.into();
+		find_inner(|| self.get_by_placeholder(placeholder.clone())).await
+	}
+	/// Wait for at least one element whose placeholder matches this method's
+	/// input, exactly. Retries `get_all_by_placeholder` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_placeholder<M: Into<TextMatch>>(
+		&self,
+		placeholder: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let placeholder = placeholder.into();
+		find_all_inner(
+			|| self.get_all_by_placeholder(placeholder.clone()),
+			|| self.get_by_placeholder(placeholder.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose placeholder contains this method's input.
+	/// Retries `get_by_placeholder_contains` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_placeholder_contains<S: AsRef<str>>(
+		&self,
+		placeholder: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_placeholder(TextMatch::contains(placeholder))
+			.await
+	}
+	/// Wait for at least one element whose placeholder contains this method's
+	/// input. Retries `get_all_by_placeholder_contains` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_placeholder_contains<S: AsRef<str>>(
+		&self,
+		placeholder: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_placeholder(TextMatch::contains(placeholder))
+			.await
+	}
+	/// Wait for the element whose test id attribute matches this method's
+	/// input, exactly. Retries `get_by_test_id` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_test_id<M: Into<TextMatch>>(
+		&self,
+		test_id: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let test_id = test_id.into();
+		find_inner(|| self.get_by_test_id(test_id.clone())).await
+	}
+	/// Wait for at least one element whose test id attribute matches this
+	/// method's input, exactly. Retries `get_all_by_test_id` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_test_id<M: Into<TextMatch>>(
+		&self,
+		test_id: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let test_id = test_id.into();
+		find_all_inner(
+			|| self.get_all_by_test_id(test_id.clone()),
+			|| self.get_by_test_id(test_id.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose test id attribute contains this method's
+	/// input. Retries `get_by_test_id_contains` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_test_id_contains<S: AsRef<str>>(
+		&self,
+		test_id: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_test_id(TextMatch::contains(test_id)).await
+	}
+	/// Wait for at least one element whose test id attribute contains this
+	/// method's input. Retries `get_all_by_test_id_contains` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_test_id_contains<S: AsRef<str>>(
+		&self,
+		test_id: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_test_id(TextMatch::contains(test_id)).await
+	}
+	/// Wait for the element whose alt text matches this method's input,
+	/// exactly. Retries `get_by_alt_text` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_alt_text<M: Into<TextMatch>>(
+		&self,
+		alt: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let alt = alt.into();
+		find_inner(|| self.get_by_alt_text(alt.clone())).await
+	}
+	/// Wait for at least one element whose alt text matches this method's
+	/// input, exactly. Retries `get_all_by_alt_text` until it finds something
+	/// or the configured timeout elapses.
+	async fn find_all_by_alt_text<M: Into<TextMatch>>(
+		&self,
+		alt: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let alt = alt.into();
+		find_all_inner(
+			|| self.get_all_by_alt_text(alt.clone()),
+			|| self.get_by_alt_text(alt.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose alt text contains this method's input.
+	/// Retries `get_by_alt_text_contains` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_alt_text_contains<S: AsRef<str>>(
+		&self,
+		alt: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_alt_text(TextMatch::contains(alt)).await
+	}
+	/// Wait for at least one element whose alt text contains this method's
+	/// input. Retries `get_all_by_alt_text_contains` until it finds something
+	/// or the configured timeout elapses.
+	async fn find_all_by_alt_text_contains<S: AsRef<str>>(
+		&self,
+		alt: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_alt_text(TextMatch::contains(alt)).await
+	}
+	/// Wait for the element whose title matches this method's input,
+	/// exactly. Retries `get_by_title` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_title<M: Into<TextMatch>>(
+		&self,
+		title: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let title = title.into();
+		find_inner(|| self.get_by_title(title.clone())).await
+	}
+	/// Wait for at least one element whose title matches this method's
+	/// input, exactly. Retries `get_all_by_title` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_title<M: Into<TextMatch>>(
+		&self,
+		title: M,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let title = title.into();
+		find_all_inner(
+			|| self.get_all_by_title(title.clone()),
+			|| self.get_by_title(title.clone()),
+		)
+		.await
+	}
+	/// Wait for the element whose title contains this method's input.
+	/// Retries `get_by_title_contains` until it succeeds or the configured
+	/// timeout elapses.
+	async fn find_by_title_contains<S: AsRef<str>>(
+		&self,
+		title: S,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.find_by_title(TextMatch::contains(title)).await
+	}
+	/// Wait for at least one element whose title contains this method's
+	/// input. Retries `get_all_by_title_contains` until it finds something or
+	/// the configured timeout elapses.
+	async fn find_all_by_title_contains<S: AsRef<str>>(
+		&self,
+		title: S,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		self.find_all_by_title(TextMatch::contains(title)).await
+	}
+	/// Query for the element matching ARIA role that also passes the filters
+	/// in `options`. See `get_by_role_with`.
+	fn query_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Result<Option<TestElement>, TestingLibraryError> {
+		let role = role.into();
+		query_one_inner(|| self.get_by_role_with(role.clone(), options.clone()))
+	}
+	/// Query for all elements matching ARIA role that also pass the filters in
+	/// `options`. See `get_all_by_role_with`.
+	fn query_all_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Vec<TestElement> {
+		self.get_all_by_role_with(role, options)
+	}
+	/// Wait for the element matching ARIA role that also passes the filters in
+	/// `options`. Retries `get_by_role_with` until it succeeds or the
+	/// configured timeout elapses.
+	async fn find_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Result<TestElement, TestingLibraryError> {
+		let role = role.into();
+		find_inner(|| self.get_by_role_with(role.clone(), options.clone())).await
+	}
+	/// Wait for at least one element matching ARIA role that also passes the
+	/// filters in `options`. Retries `get_all_by_role_with` until it finds
+	/// something or the configured timeout elapses.
+	async fn find_all_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Result<Vec<TestElement>, TestingLibraryError> {
+		let role = role.into();
+		find_all_inner(
+			|| self.get_all_by_role_with(role.clone(), options.clone()),
+			|| self.get_by_role_with(role.clone(), options.clone()),
+		)
+		.await
+	}
 }
 
 impl<T> DomQuery for T
 where
 	T: HoldsElement,
 {
-	fn get_by_text<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError> {
+	fn get_by_text<M: Into<TextMatch>>(&self, text: M) -> Result<TestElement, TestingLibraryError> {
 		self.element().get_by_text(text)
 	}
 
-	fn get_all_by_text<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+	fn get_all_by_text<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
 		self.element().get_all_by_text(text)
 	}
 
-	fn get_by_text_contains<S: AsRef<str>>(
-		&self,
-		text: S,
-	) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_text_contains(text)
+	fn get_by_id<M: Into<TextMatch>>(&self, id: M) -> Result<TestElement, TestingLibraryError> {
+		self.element().get_by_id(id)
 	}
 
-	fn get_all_by_text_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		self.element().get_all_by_text_contains(text)
+	fn get_all_by_id<M: Into<TextMatch>>(&self, id: M) -> Vec<TestElement> {
+		self.element().get_all_by_id(id)
 	}
 
-	fn get_by_id<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_id(id)
+	fn get_by_label<M: Into<TextMatch>>(
+		&self,
+		text: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.element().get_by_label(text)
 	}
 
-	fn get_all_by_id<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
-		self.element().get_all_by_id(id)
+	fn get_all_by_label<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
+		self.element().get_all_by_label(text)
 	}
 
-	fn get_by_id_contains<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_id_contains(id)
+	fn get_by_display_value<M: Into<TextMatch>>(
+		&self,
+		value: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.element().get_by_display_value(value)
 	}
 
-	fn get_all_by_id_contains<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
-		self.element().get_all_by_id_contains(id)
+	fn get_all_by_display_value<M: Into<TextMatch>>(&self, value: M) -> Vec<TestElement> {
+		self.element().get_all_by_display_value(value)
 	}
 
-	fn get_by_label<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_label(text)
+	fn get_by_role<M: Into<TextMatch>>(&self, role: M) -> Result<TestElement, TestingLibraryError> {
+		self.element().get_by_role(role)
 	}
 
-	fn get_all_by_label<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		self.element().get_all_by_label(text)
+	fn get_all_by_role<M: Into<TextMatch>>(&self, role: M) -> Vec<TestElement> {
+		self.element().get_all_by_role(role)
 	}
 
-	fn get_by_label_contains<S: AsRef<str>>(
+	fn get_by_role_with<M: Into<TextMatch>>(
 		&self,
-		text: S,
+		role: M,
+		options: ByRoleOptions,
 	) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_label_contains(text)
+		self.element().get_by_role_with(role, options)
 	}
 
-	fn get_all_by_label_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		self.element().get_all_by_label_contains(text)
+	fn get_all_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Vec<TestElement> {
+		self.element().get_all_by_role_with(role, options)
 	}
 
-	fn get_by_display_value<S: AsRef<str>>(
+	fn get_by_placeholder<M: Into<TextMatch>>(
 		&self,
-		value: S,
+		placeholder: M,
 	) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_display_value(value)
+		self.element().get_by_placeholder(placeholder)
 	}
 
-	fn get_all_by_display_value<S: AsRef<str>>(&self, value: S) -> Vec<TestElement> {
-		self.element().get_all_by_display_value(value)
+	fn get_all_by_placeholder<M: Into<TextMatch>>(&self, placeholder: M) -> Vec<TestElement> {
+		self.element().get_all_by_placeholder(placeholder)
 	}
 
-	fn get_by_role<S: AsRef<str>>(&self, role: S) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_role(role)
+	fn get_by_test_id<M: Into<TextMatch>>(
+		&self,
+		test_id: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		self.element().get_by_test_id(test_id)
 	}
 
-	fn get_all_by_role<S: AsRef<str>>(&self, role: S) -> Vec<TestElement> {
-		self.element().get_all_by_role(role)
+	fn get_all_by_test_id<M: Into<TextMatch>>(&self, test_id: M) -> Vec<TestElement> {
+		self.element().get_all_by_test_id(test_id)
 	}
 
-	fn get_by_placeholder<S: AsRef<str>>(
+	fn get_by_alt_text<M: Into<TextMatch>>(
 		&self,
-		placeholder: S,
+		alt: M,
 	) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_placeholder(placeholder)
+		self.element().get_by_alt_text(alt)
 	}
 
-	fn get_all_by_placeholder<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
-		self.element().get_all_by_placeholder(placeholder)
+	fn get_all_by_alt_text<M: Into<TextMatch>>(&self, alt: M) -> Vec<TestElement> {
+		self.element().get_all_by_alt_text(alt)
 	}
 
-	fn get_by_placeholder_contains<S: AsRef<str>>(
+	fn get_by_title<M: Into<TextMatch>>(
 		&self,
-		placeholder: S,
+		title: M,
 	) -> Result<TestElement, TestingLibraryError> {
-		self.element().get_by_placeholder_contains(placeholder)
+		self.element().get_by_title(title)
 	}
 
-	fn get_all_by_placeholder_contains<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
-		self.element().get_all_by_placeholder_contains(placeholder)
+	fn get_all_by_title<M: Into<TextMatch>>(&self, title: M) -> Vec<TestElement> {
+		self.element().get_all_by_title(title)
 	}
 }
@@ -7,138 +7,79 @@ use super::*;
 pub struct ElementWrapper<'a>(pub &'a Element);
 
 impl DomQuery for ElementWrapper<'_> {
-	fn get_by_text<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError> {
-		get_one_inner(self.get_all_by_text(text.as_ref()), "by_text", text)
-	}
-
-	fn get_all_by_text<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		get_all_text_nodes(self.0)
-			.find_parents_of_matching_text(text)
-			.into_iter()
-			.map(TestElement)
-			.collect()
-	}
-
-	fn get_by_text_contains<S: AsRef<str>>(
-		&self,
-		text: S,
-	) -> Result<TestElement, TestingLibraryError> {
+	fn get_by_text<M: Into<TextMatch>>(&self, text: M) -> Result<TestElement, TestingLibraryError> {
+		let text = text.into();
 		get_one_inner(
-			self.get_all_by_text_contains(text.as_ref()),
-			"by_text_contains",
-			text,
+			self.0,
+			self.get_all_by_text(text.clone()),
+			"by_text",
+			&text,
+			text.to_string(),
 		)
 	}
 
-	fn get_all_by_text_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
+	fn get_all_by_text<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
 		get_all_text_nodes(self.0)
-			.find_parents_of_containing_text(text)
+			.find_parents_matching(&text.into())
 			.into_iter()
 			.map(TestElement)
 			.collect()
 	}
 
-	fn get_by_id<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError> {
-		get_one_inner(self.get_all_by_id(id.as_ref()), "by_id", id)
-	}
-
-	fn get_all_by_id<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
-		let tag_names = self.0.get_elements_by_tag_name("*");
-		let mut list = Vec::new();
-		for i in 0..tag_names.length() {
-			let item = tag_names.item(i).unwrap();
-			if item.id() == id.as_ref() {
-				if let Ok(item) = item.dyn_into::<HtmlElement>() {
-					list.push(item.into());
-				}
-			}
-		}
-		list
-	}
-
-	fn get_by_id_contains<S: AsRef<str>>(&self, id: S) -> Result<TestElement, TestingLibraryError> {
+	fn get_by_id<M: Into<TextMatch>>(&self, id: M) -> Result<TestElement, TestingLibraryError> {
+		let id = id.into();
 		get_one_inner(
-			self.get_all_by_id_contains(id.as_ref()),
-			"by_id_contains",
-			id,
+			self.0,
+			self.get_all_by_id(id.clone()),
+			"by_id",
+			&id,
+			id.to_string(),
 		)
 	}
 
-	fn get_all_by_id_contains<S: AsRef<str>>(&self, id: S) -> Vec<TestElement> {
-		let tag_names = self.0.get_elements_by_tag_name("*");
-		let mut list = Vec::new();
-		for i in 0..tag_names.length() {
-			let item = tag_names.item(i).unwrap();
-			if item.id().contains(id.as_ref()) {
-				if let Ok(item) = item.dyn_into::<HtmlElement>() {
-					list.push(item.into());
-				}
-			}
-		}
-		list
-	}
-
-	fn get_by_label<S: AsRef<str>>(&self, text: S) -> Result<TestElement, TestingLibraryError> {
-		get_one_inner(self.get_all_by_label(text.as_ref()), "by_label", text)
-	}
-
-	fn get_all_by_label<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		let mut list = Vec::new();
-		let html_fors = self
-			.get_all_by_text(text)
-			.into_iter()
-			.map(|e| {
-				e.0.dyn_into::<web_sys::HtmlLabelElement>()
-					.map(|label| label.html_for())
-			})
-			.collect::<Result<Vec<_>, _>>()
-			.unwrap();
-		for html_for in html_fors {
-			list.push(self.get_by_id(&html_for).unwrap());
-		}
-		list
+	fn get_all_by_id<M: Into<TextMatch>>(&self, id: M) -> Vec<TestElement> {
+		let id = id.into();
+		get_all_matching(self.0, |item| id.matches(&item.id(), item))
 	}
 
-	fn get_by_label_contains<S: AsRef<str>>(
+	fn get_by_label<M: Into<TextMatch>>(
 		&self,
-		text: S,
+		text: M,
 	) -> Result<TestElement, TestingLibraryError> {
-		get_one_inner(
-			self.get_all_by_label_contains(text.as_ref()),
-			"by_label",
-			text,
-		)
-	}
+		let text = text.into();
+		let LabelledElements {
+			elements,
+			labels_without_control,
+		} = get_all_labelled(self.0, &text);
 
-	fn get_all_by_label_contains<S: AsRef<str>>(&self, text: S) -> Vec<TestElement> {
-		let mut list = Vec::new();
-		let html_fors = self
-			.get_all_by_text_contains(text)
-			.into_iter()
-			.map(|e| {
-				e.0.dyn_into::<web_sys::HtmlLabelElement>()
-					.map(|label| label.html_for())
-			})
-			.collect::<Result<Vec<_>, _>>()
-			.unwrap();
-		for html_for in html_fors {
-			list.push(self.get_by_id(html_for.as_str()).unwrap());
+		match labels_without_control.into_iter().next() {
+			Some(label) if elements.is_empty() => {
+				Err(TestingLibraryError::LabelWithoutControl { label })
+			}
+			_ => get_one_inner(self.0, elements, "by_label", &text, text.to_string()),
 		}
-		list
 	}
 
-	fn get_by_display_value<S: AsRef<str>>(
+	fn get_all_by_label<M: Into<TextMatch>>(&self, text: M) -> Vec<TestElement> {
+		get_all_labelled(self.0, &text.into()).elements
+	}
+
+	fn get_by_display_value<M: Into<TextMatch>>(
 		&self,
-		value: S,
+		value: M,
 	) -> Result<TestElement, TestingLibraryError> {
+		let value = value.into();
 		get_one_inner(
-			self.get_all_by_display_value(value.as_ref()),
+			self.0,
+			self.get_all_by_display_value(value.clone()),
 			"by_display_value",
-			value,
+			&value,
+			value.to_string(),
 		)
 	}
 
-	fn get_all_by_display_value<S: AsRef<str>>(&self, value: S) -> Vec<TestElement> {
+	fn get_all_by_display_value<M: Into<TextMatch>>(&self, value: M) -> Vec<TestElement> {
+		let value = value.into();
 		let elements = self
 			.0
 			.query_selector_all("input, textarea, select")
@@ -147,66 +88,87 @@ impl DomQuery for ElementWrapper<'_> {
 		for i in 0..elements.length() {
 			let item = elements.item(i).unwrap().dyn_into::<HtmlElement>().unwrap();
 			if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlTextAreaElement>() {
-				if ref_item.value() == value.as_ref() {
+				if value.matches(&ref_item.value(), &item) {
 					list.push(TestElement(item));
 				}
 			} else if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlInputElement>() {
-				if ref_item.value() == value.as_ref() {
+				if value.matches(&ref_item.value(), &item) {
 					list.push(TestElement(item));
 				}
-			} else if let Ok(item) = item.dyn_into::<web_sys::HtmlSelectElement>() {
-				if item.value() == value.as_ref() {
-					list.push(TestElement(item.into()));
+			} else if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlSelectElement>() {
+				if value.matches(&ref_item.value(), &item) {
+					list.push(TestElement(item));
 				}
 			}
 		}
 		list
 	}
 
-	fn get_by_role<S: AsRef<str>>(&self, role: S) -> Result<TestElement, TestingLibraryError> {
-		get_one_inner(self.get_all_by_role(role.as_ref()), "by_role", role)
+	fn get_by_role<M: Into<TextMatch>>(&self, role: M) -> Result<TestElement, TestingLibraryError> {
+		let role = role.into();
+		get_one_inner(
+			self.0,
+			self.get_all_by_role(role.clone()),
+			"by_role",
+			&role,
+			role.to_string(),
+		)
 	}
 
-	fn get_all_by_role<S: AsRef<str>>(&self, role: S) -> Vec<TestElement> {
-		let selector = format!("[role='{}']", role.as_ref());
-		let elements = self.0.query_selector_all(&selector).unwrap();
-		let mut list = Vec::new();
-		for i in 0..elements.length() {
-			list.push(
-				elements
-					.item(i)
-					.unwrap()
-					.dyn_into::<HtmlElement>()
-					.unwrap()
-					.into(),
-			);
-		}
-		list
+	fn get_all_by_role<M: Into<TextMatch>>(&self, role: M) -> Vec<TestElement> {
+		self.get_all_by_role_with(role, ByRoleOptions::default())
+	}
+
+	fn get_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Result<TestElement, TestingLibraryError> {
+		let role = role.into();
+		let ident = role_ident(&role, &options);
+		let list = self.get_all_by_role_with(role.clone(), options);
+		get_one_inner(self.0, list, "by_role_with", &role, ident)
+	}
+
+	fn get_all_by_role_with<M: Into<TextMatch>>(
+		&self,
+		role: M,
+		options: ByRoleOptions,
+	) -> Vec<TestElement> {
+		let role = role.into();
+		get_all_matching(self.0, |item| {
+			element_role(item).is_some_and(|value| role.matches(&value, item))
+				&& options.matches(item)
+		})
 	}
 
-	fn get_by_placeholder<S: AsRef<str>>(
+	fn get_by_placeholder<M: Into<TextMatch>>(
 		&self,
-		placeholder: S,
+		placeholder: M,
 	) -> Result<TestElement, TestingLibraryError> {
+		let placeholder = placeholder.into();
 		get_one_inner(
-			self.get_all_by_placeholder(placeholder.as_ref()),
+			self.0,
+			self.get_all_by_placeholder(placeholder.clone()),
 			"by_placeholder",
-			placeholder,
+			&placeholder,
+			placeholder.to_string(),
 		)
 	}
 
-	fn get_all_by_placeholder<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
+	fn get_all_by_placeholder<M: Into<TextMatch>>(&self, placeholder: M) -> Vec<TestElement> {
+		let placeholder = placeholder.into();
 		let elements = self.0.query_selector_all("input, textarea").unwrap();
 		let mut list = Vec::new();
 
 		for i in 0..elements.length() {
 			let item = elements.item(i).unwrap().dyn_into::<HtmlElement>().unwrap();
 			if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlTextAreaElement>() {
-				if ref_item.placeholder() == placeholder.as_ref() {
+				if placeholder.matches(&ref_item.placeholder(), &item) {
 					list.push(TestElement(item));
 				}
 			} else if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlInputElement>() {
-				if ref_item.placeholder() == placeholder.as_ref() {
+				if placeholder.matches(&ref_item.placeholder(), &item) {
 					list.push(TestElement(item));
 				}
 			}
@@ -214,34 +176,117 @@ impl DomQuery for ElementWrapper<'_> {
 		list
 	}
 
-	fn get_by_placeholder_contains<S: AsRef<str>>(
+	fn get_by_test_id<M: Into<TextMatch>>(
 		&self,
-		placeholder: S,
+		test_id: M,
 	) -> Result<TestElement, TestingLibraryError> {
+		let test_id = test_id.into();
 		get_one_inner(
-			self.get_all_by_placeholder_contains(placeholder.as_ref()),
-			"by_placeholder",
-			placeholder,
+			self.0,
+			self.get_all_by_test_id(test_id.clone()),
+			"by_test_id",
+			&test_id,
+			test_id.to_string(),
 		)
 	}
 
-	fn get_all_by_placeholder_contains<S: AsRef<str>>(&self, placeholder: S) -> Vec<TestElement> {
-		let elements = self.0.query_selector_all("input, textarea").unwrap();
-		let mut list = Vec::new();
+	fn get_all_by_test_id<M: Into<TextMatch>>(&self, test_id: M) -> Vec<TestElement> {
+		let test_id = test_id.into();
+		let attribute = get_config().test_id_attribute;
+		get_all_matching(self.0, |item| {
+			item.get_attribute(&attribute)
+				.is_some_and(|value| test_id.matches(&value, item))
+		})
+	}
 
-		for i in 0..elements.length() {
-			let item = elements.item(i).unwrap().dyn_into::<HtmlElement>().unwrap();
+	fn get_by_alt_text<M: Into<TextMatch>>(
+		&self,
+		alt: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let alt = alt.into();
+		get_one_inner(
+			self.0,
+			self.get_all_by_alt_text(alt.clone()),
+			"by_alt_text",
+			&alt,
+			alt.to_string(),
+		)
+	}
 
-			if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlTextAreaElement>() {
-				if ref_item.placeholder().contains(placeholder.as_ref()) {
-					list.push(TestElement(item));
+	fn get_all_by_alt_text<M: Into<TextMatch>>(&self, alt: M) -> Vec<TestElement> {
+		let alt = alt.into();
+		get_all_matching(self.0, |item| {
+			let supports_alt = match item.tag_name().as_str() {
+				"IMG" | "AREA" => true,
+				"INPUT" => {
+					item.get_attribute("type")
+						.is_some_and(|value| value.eq_ignore_ascii_case("image"))
 				}
-			} else if let Some(ref_item) = item.dyn_ref::<web_sys::HtmlInputElement>() {
-				if ref_item.placeholder().contains(placeholder.as_ref()) {
-					list.push(TestElement(item));
+				_ => false,
+			};
+			supports_alt
+				&& item
+					.get_attribute("alt")
+					.is_some_and(|value| alt.matches(&value, item))
+		})
+	}
+
+	fn get_by_title<M: Into<TextMatch>>(
+		&self,
+		title: M,
+	) -> Result<TestElement, TestingLibraryError> {
+		let title = title.into();
+		get_one_inner(
+			self.0,
+			self.get_all_by_title(title.clone()),
+			"by_title",
+			&title,
+			title.to_string(),
+		)
+	}
+
+	fn get_all_by_title<M: Into<TextMatch>>(&self, title: M) -> Vec<TestElement> {
+		let title = title.into();
+		let elements = self.0.query_selector_all("[title], svg > title").unwrap();
+		let mut list = Vec::new();
+		for i in 0..elements.length() {
+			let item = elements.item(i).unwrap().unchecked_into::<Element>();
+			let is_svg_title = item.tag_name() == "title";
+			let value = if is_svg_title {
+				item.text_content()
+			} else {
+				item.get_attribute("title")
+			};
+			if !value.is_some_and(|value| title.matches(&value, &item)) {
+				continue;
+			}
+			let element = if is_svg_title {
+				closest_html_element(self.0, &item)
+			} else {
+				item.dyn_into::<HtmlElement>().ok()
+			};
+			if let Some(element) = element.map(TestElement::from) {
+				if !list.contains(&element) {
+					list.push(element);
 				}
 			}
 		}
 		list
 	}
 }
+
+/// The closest ancestor of `element` inside `container` that is an
+/// `HtmlElement`, for elements like the SVG `<title>` that aren't.
+fn closest_html_element(container: &Element, element: &Element) -> Option<HtmlElement> {
+	let mut current = element.parent_element();
+	while let Some(ancestor) = current {
+		if ancestor == *container {
+			return None;
+		}
+		match ancestor.dyn_into::<HtmlElement>() {
+			Ok(element) => return Some(element),
+			Err(ancestor) => current = ancestor.unchecked_into::<Element>().parent_element(),
+		}
+	}
+	None
+}
@@ -1,28 +1,162 @@
+use std::cell::Cell;
+use std::time::Duration;
+
 use super::*;
 
 #[derive(Error, Debug, PartialEq)]
 pub enum TestingLibraryError {
-	#[error("Not Found:Attempting to find: {ident} by method {method}")]
-	NotFound { method: &'static str, ident: String },
+	#[error(
+		"Not Found:Attempting to find: {ident} by method {method}{}\n\n{dom}",
+		list_suggestions(.suggestions)
+	)]
+	NotFound {
+		method: &'static str,
+		ident: String,
+		/// The pretty printed DOM of the container that was searched.
+		dom: String,
+		/// Hints about what the query could have been, i.e close matches.
+		suggestions: Vec<String>,
+	},
 	#[error(
 		"Found more than one element by method of get_{method} with input of {ident}, if you were \
-		 expecting more than one match see the get_all_{method} version of this method instead."
+		 expecting more than one match see the get_all_{method} version of this method \
+		 instead.\n\nMatched elements:\n\n{}\n\n{dom}",
+		.matches.join("\n\n")
+	)]
+	MoreThanOne {
+		method: &'static str,
+		ident: String,
+		/// The pretty printed HTML of every element that matched.
+		matches: Vec<String>,
+		/// The pretty printed DOM of the container that was searched.
+		dom: String,
+	},
+	#[error(
+		"Found a label with the text of: {label}, however no form control was found associated to \
+		 that label. Make sure you're using the \"for\" attribute or \"aria-labelledby\" \
+		 attribute correctly."
 	)]
-	MoreThanOne { method: &'static str, ident: String },
+	LabelWithoutControl { label: String },
+	#[error("The element given to wait_for_element_to_be_removed is still in the document: {html}")]
+	NotRemoved { html: String },
+	#[error(
+		"The element given to wait_for_element_to_be_removed was already removed. Make sure the \
+		 element is in the document before waiting for it to be removed."
+	)]
+	AlreadyRemoved,
+	#[error(
+		"Unable to perform pointer interaction as the element has `pointer-events: none`: {html}"
+	)]
+	PointerEventsDisabled { html: String },
+	#[error("Expected an enabled input, textarea or contenteditable element, found: {html}")]
+	NotEditable { html: String },
+	#[error("Expected a file input or a label of one, found: {html}")]
+	NotFileInput { html: String },
+	#[error("Invalid keyboard input {input:?}: {reason}")]
+	InvalidKeyboardInput { input: String, reason: String },
+	#[error("Timed out after {timeout:?} waiting for {waiting_for}. Last error: {last_error}")]
+	Timeout {
+		timeout: Duration,
+		/// What was being waited for, i.e "an element".
+		waiting_for: &'static str,
+		last_error: Box<TestingLibraryError>,
+	},
+}
+
+thread_local! {
+	/// Whether query errors are being built without the DOM and suggestions.
+	/// See [`with_brief_errors`].
+	static BRIEF_ERRORS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Call `f`, building the `NotFound` and `MoreThanOne` errors of the queries
+/// it makes without the pretty printed DOM and suggestions. Those are slow to
+/// build on large DOMs, and are thrown away when a query is retried.
+pub(crate) fn with_brief_errors<T>(f: impl FnOnce() -> T) -> T {
+	/// Restores whether errors were brief when dropped, even if `f` panics.
+	struct RestoreBriefErrors(bool);
+
+	impl Drop for RestoreBriefErrors {
+		fn drop(&mut self) {
+			BRIEF_ERRORS.set(self.0);
+		}
+	}
+
+	let _restore = RestoreBriefErrors(BRIEF_ERRORS.replace(true));
+	f()
+}
+
+/// The pretty printed HTML of `element` for an error, unless errors are
+/// brief.
+fn error_html(element: &Element) -> String {
+	if BRIEF_ERRORS.get() {
+		String::new()
+	} else {
+		pretty_html(element, get_config().max_dom_length)
+	}
 }
 
 impl TestingLibraryError {
-	pub(crate) fn more_than_one(method: &'static str, ident: String) -> Self {
-		Self::MoreThanOne { method, ident }
+	pub(crate) fn more_than_one(
+		container: &Element,
+		method: &'static str,
+		ident: String,
+		matches: &[TestElement],
+	) -> Self {
+		Self::MoreThanOne {
+			method,
+			ident,
+			matches: matches.iter().map(|element| error_html(element)).collect(),
+			dom: error_html(container),
+		}
+	}
+
+	pub(crate) fn not_found(container: &Element, method: &'static str, ident: String) -> Self {
+		Self::NotFound {
+			method,
+			ident,
+			dom: error_html(container),
+			suggestions: Vec::new(),
+		}
 	}
 
-	pub(crate) fn not_found(method: &'static str, ident: String) -> Self {
-		Self::NotFound { method, ident }
+	/// Add the suggestions returned by `suggestions` to a `NotFound` error,
+	/// unless errors are brief. Other errors are unchanged.
+	pub(crate) fn with_suggestions<F>(mut self, suggestions: F) -> Self
+	where
+		F: FnOnce() -> Vec<String>,
+	{
+		if let Self::NotFound {
+			suggestions: existing,
+			..
+		} = &mut self
+		{
+			if !BRIEF_ERRORS.get() {
+				existing.extend(suggestions());
+			}
+		}
+		self
+	}
+
+	pub(crate) fn timeout(timeout: Duration, waiting_for: &'static str, last_error: Self) -> Self {
+		Self::Timeout {
+			timeout,
+			waiting_for,
+			last_error: Box::new(last_error),
+		}
 	}
 }
+fn list_suggestions(suggestions: &[String]) -> String {
+	suggestions
+		.iter()
+		.flat_map(|suggestion| ["\n\n", suggestion.as_str()])
+		.collect()
+}
+
 pub trait TestingLibraryErrorTrait {
 	fn is_not_found(&self) -> bool;
 	fn is_more_than_one(&self) -> bool;
+	fn is_timeout(&self) -> bool;
 }
 
 impl TestingLibraryErrorTrait for TestingLibraryError {
@@ -33,6 +167,10 @@ impl TestingLibraryErrorTrait for TestingLibraryError {
 	fn is_more_than_one(&self) -> bool {
 		matches!(self, TestingLibraryError::MoreThanOne { .. })
 	}
+
+	fn is_timeout(&self) -> bool {
+		matches!(self, TestingLibraryError::Timeout { .. })
+	}
 }
 
 impl<T> TestingLibraryErrorTrait for Result<T, TestingLibraryError> {
@@ -49,4 +187,11 @@ impl<T> TestingLibraryErrorTrait for Result<T, TestingLibraryError> {
 			Err(err) => matches!(err, TestingLibraryError::MoreThanOne { .. }),
 		}
 	}
+
+	fn is_timeout(&self) -> bool {
+		match &self {
+			Ok(_) => false,
+			Err(err) => matches!(err, TestingLibraryError::Timeout { .. }),
+		}
+	}
 }
@@ -0,0 +1,297 @@
+//! Dispatch DOM events on elements.
+//!
+//! Every event has two functions, i.e `click(&element)` and
+//! `click_with(&element, options)`. The first uses the default
+//! [`EventOptions`], with `bubbles` and `cancelable` set to what the browser
+//! would use for that event. Every function returns `true` if the event was
+//! cancelled by a handler calling `prevent_default`.
+//!
+//! ```rust
+//! # #[cfg(target_arch = "wasm32")]
+//! # mod hidden_example {
+//! use leptos_testing_library::prelude::*;
+//!
+//! fn submit_with_enter(render: &impl DomQuery) {
+//! 	let input = render.get_by_role("textbox").unwrap();
+//! 	fire_event::focus(&input);
+//! 	fire_event::key_down_with(
+//! 		&input,
+//! 		EventOptions {
+//! 			key: "Enter".into(),
+//! 			code: "Enter".into(),
+//! 			key_code: 13,
+//! 			..Default::default()
+//! 		},
+//! 	);
+//! }
+//! # }
+//! ```
+
+use wasm_bindgen::JsValue;
+use web_sys::DataTransfer;
+use web_sys::Event;
+use web_sys::EventTarget;
+
+use super::*;
+
+/// Options used to initialize an event. Fields that don't apply to an event
+/// are ignored, i.e `key` is only used by keyboard events.
+// The flags mirror the fields of the DOM event init dictionaries.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default)]
+pub struct EventOptions {
+	/// Whether the event bubbles. Defaults to what the browser uses for the
+	/// event.
+	pub bubbles: Option<bool>,
+	/// Whether the event can be cancelled. Defaults to what the browser uses
+	/// for the event.
+	pub cancelable: Option<bool>,
+	/// Whether the event propagates across shadow DOM boundaries.
+	pub composed: bool,
+	/// The `detail` of UI events, i.e the click count for mouse events.
+	pub detail: i32,
+	/// The `key` of keyboard events, i.e `"a"` or `"Enter"`.
+	pub key: String,
+	/// The `code` of keyboard events, i.e `"KeyA"` or `"Enter"`.
+	pub code: String,
+	/// The legacy `keyCode` and `which` of keyboard events.
+	pub key_code: u32,
+	/// The legacy `charCode` of `keypress` events.
+	pub char_code: u32,
+	/// Whether a keyboard event is from a key being held down.
+	pub repeat: bool,
+	pub alt_key: bool,
+	pub ctrl_key: bool,
+	pub meta_key: bool,
+	pub shift_key: bool,
+	/// The mouse button that changed, `0` is the main button.
+	pub button: i16,
+	/// The mouse buttons that are pressed, as a bitmask.
+	pub buttons: u16,
+	pub client_x: i32,
+	pub client_y: i32,
+	pub screen_x: i32,
+	pub screen_y: i32,
+	/// The other element involved in focus and mouse events, i.e the element
+	/// losing focus for `focus`.
+	pub related_target: Option<EventTarget>,
+	pub pointer_id: i32,
+	/// The `pointerType` of pointer events, i.e `"mouse"` or `"touch"`.
+	pub pointer_type: String,
+	pub is_primary: bool,
+	pub delta_x: f64,
+	pub delta_y: f64,
+	pub delta_z: f64,
+	/// The unit of the wheel deltas, `0` for pixels.
+	pub delta_mode: u32,
+	/// The text inserted by input events.
+	pub data: Option<String>,
+	/// The `inputType` of input events, i.e `"insertText"`.
+	pub input_type: String,
+	/// The data of clipboard and drag events, and of input events that insert
+	/// it.
+	pub data_transfer: Option<DataTransfer>,
+}
+
+impl EventOptions {
+	fn init(&self, bubbles: bool, cancelable: bool) -> js_sys::Object {
+		let init = js_sys::Object::new();
+		let set = |key: &str, value: JsValue| {
+			_ = js_sys::Reflect::set(&init, &key.into(), &value);
+		};
+
+		set("bubbles", self.bubbles.unwrap_or(bubbles).into());
+		set("cancelable", self.cancelable.unwrap_or(cancelable).into());
+		set("composed", self.composed.into());
+		set("detail", self.detail.into());
+		set("key", self.key.as_str().into());
+		set("code", self.code.as_str().into());
+		set("keyCode", self.key_code.into());
+		set("which", self.key_code.into());
+		set("charCode", self.char_code.into());
+		set("repeat", self.repeat.into());
+		set("altKey", self.alt_key.into());
+		set("ctrlKey", self.ctrl_key.into());
+		set("metaKey", self.meta_key.into());
+		set("shiftKey", self.shift_key.into());
+		set("button", self.button.into());
+		set("buttons", self.buttons.into());
+		set("clientX", self.client_x.into());
+		set("clientY", self.client_y.into());
+		set("screenX", self.screen_x.into());
+		set("screenY", self.screen_y.into());
+		set(
+			"relatedTarget",
+			self.related_target
+				.clone()
+				.map_or(JsValue::NULL, Into::into),
+		);
+		set("pointerId", self.pointer_id.into());
+		set("pointerType", self.pointer_type.as_str().into());
+		set("isPrimary", self.is_primary.into());
+		set("deltaX", self.delta_x.into());
+		set("deltaY", self.delta_y.into());
+		set("deltaZ", self.delta_z.into());
+		set("deltaMode", self.delta_mode.into());
+		set(
+			"data",
+			self.data.as_deref().map_or(JsValue::NULL, Into::into),
+		);
+		set("inputType", self.input_type.as_str().into());
+		let data_transfer = self.data_transfer.clone().map_or(JsValue::NULL, Into::into);
+		set("clipboardData", data_transfer.clone());
+		set("dataTransfer", data_transfer);
+
+		init
+	}
+}
+
+/// The interface used to construct an event.
+#[derive(Clone, Copy)]
+enum EventKind {
+	Clipboard,
+	Drag,
+	Event,
+	Focus,
+	Input,
+	Keyboard,
+	Mouse,
+	Pointer,
+	Wheel,
+}
+
+fn create_event(
+	kind: EventKind,
+	event_type: &str,
+	init: &js_sys::Object,
+) -> Result<Event, JsValue> {
+	match kind {
+		EventKind::Clipboard => {
+			web_sys::ClipboardEvent::new_with_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Drag => {
+			web_sys::DragEvent::new_with_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Event => Event::new_with_event_init_dict(event_type, init.unchecked_ref()),
+		EventKind::Focus => {
+			web_sys::FocusEvent::new_with_focus_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Input => {
+			web_sys::InputEvent::new_with_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Keyboard => {
+			web_sys::KeyboardEvent::new_with_keyboard_event_init_dict(
+				event_type,
+				init.unchecked_ref(),
+			)
+			.map(Into::into)
+		}
+		EventKind::Mouse => {
+			web_sys::MouseEvent::new_with_mouse_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Pointer => {
+			web_sys::PointerEvent::new_with_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+		EventKind::Wheel => {
+			web_sys::WheelEvent::new_with_event_init_dict(event_type, init.unchecked_ref())
+				.map(Into::into)
+		}
+	}
+}
+
+/// Dispatch an already constructed event on `element`. Returns `true` if the
+/// event was cancelled.
+pub fn dispatch(element: &Element, event: &Event) -> bool {
+	!element
+		.dispatch_event(event)
+		.expect("the event to be dispatched")
+}
+
+fn fire(
+	element: &Element,
+	kind: EventKind,
+	event_type: &str,
+	(bubbles, cancelable): (bool, bool),
+	options: &EventOptions,
+) -> bool {
+	let init = options.init(bubbles, cancelable);
+	let event = create_event(kind, event_type, &init).expect("the event to be created");
+	dispatch(element, &event)
+}
+
+macro_rules! events {
+	($($name:ident, $name_with:ident => $kind:ident($event_type:literal, $bubbles:literal, $cancelable:literal);)*) => {
+		$(
+			#[doc = concat!("Fire a `", $event_type, "` event on `element`. Returns `true` if the event was cancelled.")]
+			pub fn $name(element: &Element) -> bool {
+				$name_with(element, EventOptions::default())
+			}
+
+			#[doc = concat!("Fire a `", $event_type, "` event on `element` with custom [`EventOptions`]. Returns `true` if the event was cancelled.")]
+			pub fn $name_with(element: &Element, options: EventOptions) -> bool {
+				fire(element, EventKind::$kind, $event_type, ($bubbles, $cancelable), &options)
+			}
+		)*
+	};
+}
+
+events! {
+	// Mouse events.
+	click, click_with => Mouse("click", true, true);
+	dbl_click, dbl_click_with => Mouse("dblclick", true, true);
+	context_menu, context_menu_with => Mouse("contextmenu", true, true);
+	mouse_down, mouse_down_with => Mouse("mousedown", true, true);
+	mouse_up, mouse_up_with => Mouse("mouseup", true, true);
+	mouse_move, mouse_move_with => Mouse("mousemove", true, true);
+	mouse_over, mouse_over_with => Mouse("mouseover", true, true);
+	mouse_out, mouse_out_with => Mouse("mouseout", true, true);
+	mouse_enter, mouse_enter_with => Mouse("mouseenter", false, false);
+	mouse_leave, mouse_leave_with => Mouse("mouseleave", false, false);
+	// Pointer events.
+	pointer_down, pointer_down_with => Pointer("pointerdown", true, true);
+	pointer_up, pointer_up_with => Pointer("pointerup", true, true);
+	pointer_move, pointer_move_with => Pointer("pointermove", true, true);
+	pointer_over, pointer_over_with => Pointer("pointerover", true, true);
+	pointer_out, pointer_out_with => Pointer("pointerout", true, true);
+	pointer_enter, pointer_enter_with => Pointer("pointerenter", false, false);
+	pointer_leave, pointer_leave_with => Pointer("pointerleave", false, false);
+	pointer_cancel, pointer_cancel_with => Pointer("pointercancel", true, false);
+	// Drag events.
+	drag, drag_with => Drag("drag", true, true);
+	drag_start, drag_start_with => Drag("dragstart", true, true);
+	drag_end, drag_end_with => Drag("dragend", true, false);
+	drag_enter, drag_enter_with => Drag("dragenter", true, true);
+	drag_over, drag_over_with => Drag("dragover", true, true);
+	drag_leave, drag_leave_with => Drag("dragleave", true, false);
+	drop, drop_with => Drag("drop", true, true);
+	// Keyboard events.
+	key_down, key_down_with => Keyboard("keydown", true, true);
+	key_press, key_press_with => Keyboard("keypress", true, true);
+	key_up, key_up_with => Keyboard("keyup", true, true);
+	// Focus events.
+	focus, focus_with => Focus("focus", false, false);
+	blur, blur_with => Focus("blur", false, false);
+	focus_in, focus_in_with => Focus("focusin", true, false);
+	focus_out, focus_out_with => Focus("focusout", true, false);
+	// Form events.
+	before_input, before_input_with => Input("beforeinput", true, true);
+	input, input_with => Input("input", true, false);
+	change, change_with => Event("change", true, false);
+	submit, submit_with => Event("submit", true, true);
+	reset, reset_with => Event("reset", true, true);
+	invalid, invalid_with => Event("invalid", false, true);
+	select, select_with => Event("select", true, false);
+	// Clipboard events.
+	copy, copy_with => Clipboard("copy", true, true);
+	cut, cut_with => Clipboard("cut", true, true);
+	paste, paste_with => Clipboard("paste", true, true);
+	// Other events.
+	scroll, scroll_with => Event("scroll", false, false);
+	wheel, wheel_with => Wheel("wheel", true, true);
+}
@@ -1,22 +1,73 @@
 use super::*;
 
+/// Get the only element in `list`, the result of a `get_all_by_X` query in
+/// `container`. The errors list what was rendered, and `NotFound` errors
+/// suggest what `text` could have been. See
+/// [`Config::warn_on_lower_priority_queries`] for the warning when it's found.
 pub(crate) fn get_one_inner<S: AsRef<str>>(
+	container: &Element,
 	list: Vec<TestElement>,
 	method: &'static str,
+	text: &TextMatch,
 	ident: S,
 ) -> Result<TestElement, TestingLibraryError> {
 	if list.len() > 1 {
 		Err(TestingLibraryError::more_than_one(
+			container,
 			method,
 			ident.as_ref().to_string(),
-		))
-	} else if list.is_empty() {
-		Err(TestingLibraryError::not_found(
-			method,
-			ident.as_ref().to_string(),
+			&list,
 		))
 	} else {
-		Ok(list.first().cloned().unwrap())
+		let element = list.into_iter().next().ok_or_else(|| {
+			TestingLibraryError::not_found(container, method, ident.as_ref().to_string())
+				.with_suggestions(|| suggestions(container, method, text))
+		})?;
+		warn_on_lower_priority_query(container, &element, method, ident.as_ref());
+		Ok(element)
+	}
+}
+
+/// Make the `get_by_X` query `get` as the matching `query_by_X` query, where
+/// not finding anything isn't an error. `get` builds brief errors, since a
+/// `NotFound` error is thrown away, and is only called again to build the
+/// full error when it fails otherwise.
+pub(crate) fn query_one_inner<F>(get: F) -> Result<Option<TestElement>, TestingLibraryError>
+where
+	F: Fn() -> Result<TestElement, TestingLibraryError>,
+{
+	match with_brief_errors(&get) {
+		Ok(element) => Ok(Some(element)),
+		Err(TestingLibraryError::NotFound { .. }) => Ok(None),
+		Err(_) => get().map(Some),
+	}
+}
+
+/// Get every element inside `container` that passes `predicate`.
+pub(crate) fn get_all_matching<F>(container: &Element, predicate: F) -> Vec<TestElement>
+where
+	F: Fn(&Element) -> bool,
+{
+	let tag_names = container.get_elements_by_tag_name("*");
+	let mut list = Vec::new();
+	for i in 0..tag_names.length() {
+		let item = tag_names.item(i).unwrap();
+		if predicate(&item) {
+			if let Ok(item) = item.dyn_into::<HtmlElement>() {
+				list.push(item.into());
+			}
+		}
+	}
+	list
+}
+
+/// The identifier used in errors for the `get_by_role_with` series.
+pub(crate) fn role_ident(role: &TextMatch, options: &ByRoleOptions) -> String {
+	let filters = options.to_string();
+	if filters.is_empty() {
+		role.to_string()
+	} else {
+		format!("{role} ({filters})")
 	}
 }
 
@@ -39,20 +90,25 @@ pub(crate) fn get_all_text_nodes(node: &Node) -> TextNodes {
 }
 pub(crate) struct TextNodes(Vec<Node>);
 impl TextNodes {
-	pub(crate) fn join_text(&self, join_on: String) -> String {
+	pub(crate) fn join_text(&self, join_on: &str) -> String {
 		self.0
 			.iter()
 			.map(|node| node.text_content().unwrap())
 			.collect::<Vec<String>>()
-			.join(&join_on)
+			.join(join_on)
 	}
 
-	pub(crate) fn find_parents_of_matching_text<S: AsRef<str>>(&self, text: S) -> Vec<HtmlElement> {
-		let mut list = vec![];
+	/// Get the elements whose own text, the text of their direct child text
+	/// nodes, matches `text`. `<script>` and `<style>` elements are ignored.
+	pub(crate) fn find_parents_matching(&self, text: &TextMatch) -> Vec<HtmlElement> {
+		let mut list: Vec<HtmlElement> = vec![];
 		for node in &self.0 {
 			if let Some(element) = node.parent_element() {
 				if let Some(html_element) = element.dyn_ref::<HtmlElement>() {
-					if html_element.inner_text() == text.as_ref() {
+					if !list.contains(html_element)
+						&& !matches!(element.tag_name().as_str(), "SCRIPT" | "STYLE")
+						&& text.matches(&own_text(&element), &element)
+					{
 						list.push(html_element.clone());
 					}
 				}
@@ -60,27 +116,14 @@ impl TextNodes {
 		}
 		list
 	}
+}
 
-	pub(crate) fn find_parents_of_containing_text<S: AsRef<str>>(
-		&self,
-		text: S,
-	) -> Vec<HtmlElement> {
-		let mut list = vec![];
-		for node in &self.0 {
-			if node
-				.text_content()
-				.expect("Text node to have text content,")
-				.contains(text.as_ref())
-			{
-				if let Some(element) = node.parent_element() {
-					if let Some(html_element) = element.dyn_ref::<HtmlElement>() {
-						if html_element.inner_text().contains(text.as_ref()) {
-							list.push(html_element.clone());
-						}
-					}
-				}
-			}
-		}
-		list
-	}
+/// The text of the direct child text nodes of `element`, joined together.
+pub(crate) fn own_text(element: &Element) -> String {
+	let children = element.child_nodes();
+	(0..children.length())
+		.filter_map(|i| children.item(i))
+		.filter(|node| node.node_type() == Node::TEXT_NODE)
+		.map(|node| node.text_content().unwrap_or_default())
+		.collect()
 }
@@ -0,0 +1,107 @@
+use leptos::prelude::document;
+
+use super::*;
+
+/// The result of looking up the elements labelled by some text.
+pub(crate) struct LabelledElements {
+	/// The labelled elements, in document order.
+	pub(crate) elements: Vec<TestElement>,
+	/// The text of every matching `<label>` that isn't associated with a form
+	/// control.
+	pub(crate) labels_without_control: Vec<String>,
+}
+
+/// Find every element inside `container` that is labelled by `text`, either
+/// through a `<label>` (with a `for` attribute or wrapping the control), an
+/// `aria-labelledby` reference, or an `aria-label` attribute.
+pub(crate) fn get_all_labelled(container: &Element, text: &TextMatch) -> LabelledElements {
+	let mut controls = Vec::new();
+	let mut labels_without_control = Vec::new();
+	let labels = container.get_elements_by_tag_name("label");
+	for i in 0..labels.length() {
+		let label = labels.item(i).unwrap();
+		let label_text = label_text(&label);
+		if !text.matches(&label_text, &label) {
+			continue;
+		}
+
+		match label
+			.dyn_ref::<web_sys::HtmlLabelElement>()
+			.and_then(web_sys::HtmlLabelElement::control)
+		{
+			Some(control) => controls.push(Element::from(control)),
+			// Controls labelled through `aria-labelledby` are found below.
+			None if label.id().is_empty() || !is_referenced(container, &label.id()) => {
+				labels_without_control.push(default_normalizer(&label_text));
+			}
+			None => {}
+		}
+	}
+
+	let elements = get_all_matching(container, |item| {
+		controls.contains(item)
+			|| item
+				.get_attribute("aria-label")
+				.is_some_and(|label| text.matches(&label, item))
+			|| item
+				.get_attribute("aria-labelledby")
+				.is_some_and(|ids| is_labelled_by(&ids, text))
+	});
+
+	LabelledElements {
+		elements,
+		labels_without_control,
+	}
+}
+
+/// The text of a label, leaving out the value of any control it wraps.
+fn label_text(label: &Element) -> String {
+	let children = label.child_nodes();
+	let mut text = String::new();
+	for i in 0..children.length() {
+		let child = children.item(i).unwrap();
+		let is_control = child.dyn_ref::<Element>().is_some_and(|element| {
+			matches!(
+				element.tag_name().as_str(),
+				"BUTTON" | "INPUT" | "METER" | "OUTPUT" | "PROGRESS" | "SELECT" | "TEXTAREA"
+			)
+		});
+		if !is_control {
+			text.push_str(&child.text_content().unwrap_or_default());
+		}
+	}
+	text
+}
+
+/// Returns `true` if the text of the elements referenced by the
+/// space-separated `ids`, either one of them or all of them joined together,
+/// matches `text`.
+fn is_labelled_by(ids: &str, text: &TextMatch) -> bool {
+	let document = document();
+	let labels = ids
+		.split_whitespace()
+		.filter_map(|id| document.get_element_by_id(id))
+		.collect::<Vec<_>>();
+	let label_texts = labels
+		.iter()
+		.map(|label| label.text_content().unwrap_or_default())
+		.collect::<Vec<_>>();
+
+	labels
+		.iter()
+		.zip(&label_texts)
+		.any(|(label, label_text)| text.matches(label_text, label))
+		|| labels
+			.first()
+			.is_some_and(|label| text.matches(&label_texts.join(" "), label))
+}
+
+/// Returns `true` if some element inside `container` references `id` from its
+/// `aria-labelledby` attribute.
+fn is_referenced(container: &Element, id: &str) -> bool {
+	!get_all_matching(container, |item| {
+		item.get_attribute("aria-labelledby")
+			.is_some_and(|ids| ids.split_whitespace().any(|value| value == id))
+	})
+	.is_empty()
+}
@@ -1,3 +1,5 @@
+use accessibility::*;
+pub use config::*;
 use derive_more::Deref;
 use derive_more::DerefMut;
 use derive_more::From;
@@ -5,26 +7,64 @@ use derive_more::Into;
 pub use dom_query::*;
 pub use element_wrapper::*;
 pub use error::*;
+use fire_event::EventOptions;
 use internal::*;
+use label::*;
+pub use pretty::*;
+pub use role::*;
+pub use screen::*;
+pub use snapshot::*;
+use suggest::*;
+pub use suggested_query::*;
 pub use test_element::*;
+pub use text_match::*;
 use thiserror::Error;
+pub(crate) use wait::*;
 use wasm_bindgen::JsCast;
 use web_sys::Element;
 use web_sys::HtmlElement;
 use web_sys::Node;
 
+pub mod accessibility;
+pub mod assertions;
+mod config;
 mod dom_query;
 mod element_wrapper;
 mod error;
+pub mod fire_event;
 mod internal;
+mod label;
+mod pretty;
+mod role;
+mod screen;
+mod snapshot;
+mod suggest;
+mod suggested_query;
 mod test_element;
+mod text_match;
+pub mod user_event;
+mod wait;
 
 pub mod prelude {
+	pub use super::ByRoleOptions;
+	pub use super::Config;
 	pub use super::DomQuery;
 	pub use super::ElementWrapper;
 	pub use super::HoldsElement;
+	pub use super::PrettyOptions;
 	pub use super::TestElement;
 	pub use super::TestingLibraryErrorTrait;
+	pub use super::TextMatch;
+	pub use super::assertions::expect;
+	pub use super::configure;
+	pub use super::dom_snapshot;
+	pub use super::fire_event;
+	pub use super::fire_event::EventOptions;
+	pub use super::get_config;
+	pub use super::pretty_dom;
+	pub use super::screen;
+	pub use super::user_event;
+	pub use super::within;
 }
 
 // We need to use unit_tests feature because wasm_pack can only run either an
@@ -51,7 +91,7 @@ pub mod test {
 			.append_child(&wrapper.clone().into())
 			.unwrap();
 		let text_nodes = get_all_text_nodes(&document);
-		let results = text_nodes.find_parents_of_matching_text("hello");
+		let results = text_nodes.find_parents_matching(&TextMatch::from("hello"));
 		assert!(
 			(results.len() == 1),
 			"{}",
@@ -77,7 +117,7 @@ pub mod test {
 			.append_child(&wrapper.into())
 			.unwrap();
 		let text_nodes = get_all_text_nodes(&document);
-		let results = text_nodes.find_parents_of_containing_text("other");
+		let results = text_nodes.find_parents_matching(&TextMatch::contains("other"));
 		assert!(
 			(results.len() == 1),
 			"{}",
@@ -0,0 +1,195 @@
+use std::fmt::Write;
+
+use super::*;
+
+/// Elements that never have children or a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+	"area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+	"wbr",
+];
+
+/// Attributes Leptos adds to elements for hydration.
+const HYDRATION_ATTRIBUTES: &[&str] = &["data-hk"];
+
+const CYAN: &str = "\x1b[36m";
+const YELLOW: &str = "\x1b[33m";
+const GREEN: &str = "\x1b[32m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// Options for [`pretty_dom`]. The default length is taken from the global
+/// [`Config`].
+#[derive(Clone, Debug)]
+pub struct PrettyOptions {
+	/// The most characters printed before the output is truncated. Defaults
+	/// to [`Config::max_dom_length`].
+	pub max_length: usize,
+	/// The number of spaces each level of nesting is indented by. Defaults
+	/// to 2.
+	pub indent: usize,
+	/// Print the attributes of each element in alphabetical order, rather
+	/// than in the order they were set. Defaults to `true`.
+	pub sort_attributes: bool,
+	/// Color tags, attributes and comments with ANSI escape codes, for
+	/// terminals. Defaults to `false`.
+	pub highlight: bool,
+	/// Leave out comments, which include the markers Leptos inserts around
+	/// dynamic content, and hydration attributes. Defaults to `true`.
+	pub filter_comments: bool,
+}
+
+impl Default for PrettyOptions {
+	fn default() -> Self {
+		Self {
+			max_length: get_config().max_dom_length,
+			indent: 2,
+			sort_attributes: true,
+			highlight: false,
+			filter_comments: true,
+		}
+	}
+}
+
+/// Format `node` as HTML with one element, text or comment per line,
+/// indented by nesting.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::prelude::*;
+///
+/// fn print_form(render: &impl DomQuery) {
+/// 	let form = render.get_by_role("form").unwrap();
+/// 	let html = pretty_dom(
+/// 		&form,
+/// 		&PrettyOptions {
+/// 			highlight: true,
+/// 			..Default::default()
+/// 		},
+/// 	);
+/// 	leptos::logging::log!("{html}");
+/// }
+/// # }
+/// ```
+pub fn pretty_dom(node: &Node, options: &PrettyOptions) -> String {
+	let mut lines = Vec::new();
+	write_node(node, 0, options, &mut lines);
+	let html = lines.join("\n");
+	let mut output = truncate(&html, options.max_length);
+	// Truncating can cut a color off before it is reset.
+	if options.highlight && output.len() < html.len() {
+		output.push_str(RESET);
+	}
+	output
+}
+
+/// Format `node` with the default options and `max_length`, for errors.
+pub(crate) fn pretty_html(node: &Node, max_length: usize) -> String {
+	pretty_dom(
+		node,
+		&PrettyOptions {
+			max_length,
+			..Default::default()
+		},
+	)
+}
+
+fn write_node(node: &Node, depth: usize, options: &PrettyOptions, lines: &mut Vec<String>) {
+	let indent = " ".repeat(depth * options.indent);
+	let color = |code: &'static str| if options.highlight { code } else { "" };
+	let (cyan, yellow, green, gray, reset) = (
+		color(CYAN),
+		color(YELLOW),
+		color(GREEN),
+		color(GRAY),
+		color(RESET),
+	);
+
+	match node.node_type() {
+		Node::TEXT_NODE => {
+			let text = default_normalizer(&node.text_content().unwrap_or_default());
+			if !text.is_empty() {
+				lines.push(format!("{indent}{}", escape(&text)));
+			}
+		}
+		Node::COMMENT_NODE if !options.filter_comments => {
+			let text = node.text_content().unwrap_or_default();
+			lines.push(format!("{indent}{gray}<!--{text}-->{reset}"));
+		}
+		Node::ELEMENT_NODE => {
+			let element = node.unchecked_ref::<Element>();
+			let tag = element.tag_name().to_lowercase();
+			let attributes = element.attributes();
+			let mut attributes = (0..attributes.length())
+				.filter_map(|index| attributes.item(index))
+				.map(|attribute| (attribute.name(), attribute.value()))
+				.filter(|(name, _)| {
+					!options.filter_comments || !HYDRATION_ATTRIBUTES.contains(&name.as_str())
+				})
+				.collect::<Vec<_>>();
+			if options.sort_attributes {
+				attributes.sort();
+			}
+			let attributes = attributes
+				.iter()
+				.fold(String::new(), |mut output, (name, value)| {
+					let value = escape(value);
+					_ = write!(output, " {yellow}{name}{reset}={green}\"{value}\"{reset}");
+					output
+				});
+			let children = child_nodes(node, options);
+			let open = format!("{indent}{cyan}<{tag}{reset}{attributes}{cyan}>{reset}");
+			let close = format!("{cyan}</{tag}>{reset}");
+
+			if VOID_ELEMENTS.contains(&tag.as_str()) {
+				lines.push(open);
+			} else if children.is_empty() {
+				lines.push(format!("{open}{close}"));
+			} else {
+				lines.push(open);
+				for child in &children {
+					write_node(child, depth + 1, options, lines);
+				}
+				lines.push(format!("{indent}{close}"));
+			}
+		}
+		Node::DOCUMENT_NODE | Node::DOCUMENT_FRAGMENT_NODE => {
+			for child in &child_nodes(node, options) {
+				write_node(child, depth, options, lines);
+			}
+		}
+		_ => {}
+	}
+}
+
+/// The children of `node` that are printed: elements, text that isn't only
+/// whitespace, and comments unless they're filtered.
+fn child_nodes(node: &Node, options: &PrettyOptions) -> Vec<Node> {
+	let children = node.child_nodes();
+	(0..children.length())
+		.filter_map(|index| children.item(index))
+		.filter(|child| {
+			match child.node_type() {
+				Node::ELEMENT_NODE => true,
+				Node::TEXT_NODE => !child.text_content().unwrap_or_default().trim().is_empty(),
+				Node::COMMENT_NODE => !options.filter_comments,
+				_ => false,
+			}
+		})
+		.collect()
+}
+
+fn truncate(text: &str, max_length: usize) -> String {
+	match text.char_indices().nth(max_length) {
+		Some((index, _)) => format!("{}...", &text[..index]),
+		None => text.to_string(),
+	}
+}
+
+/// Escape `&`, `<` and `"` in text and attribute values, so they can't be
+/// mistaken for markup.
+fn escape(text: &str) -> String {
+	text.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('"', "&quot;")
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+
+use super::*;
+
+/// Get the ARIA role of an element. An explicit `role` attribute wins, in
+/// which case only its first token is used, otherwise this falls back to the
+/// implicit role from [`implicit_role`].
+pub fn element_role(element: &Element) -> Option<String> {
+	element
+		.get_attribute("role")
+		.and_then(|role| role.split_whitespace().next().map(str::to_string))
+		.or_else(|| implicit_role(element).map(str::to_string))
+}
+
+/// Get the implicit ARIA role of an element, as defined by the HTML-AAM
+/// mapping. i.e `<button>` is a `button`, `<a href="/">` is a `link` and
+/// `<input type="checkbox">` is a `checkbox`. Returns `None` for elements
+/// without a role, like `<div>` or `<span>`.
+pub fn implicit_role(element: &Element) -> Option<&'static str> {
+	let has = |attribute: &str| element.has_attribute(attribute);
+	let role = match element.tag_name().to_lowercase().as_str() {
+		"a" | "area" if has("href") => "link",
+		"address" | "details" | "fieldset" | "optgroup" => "group",
+		"article" => "article",
+		"aside" => "complementary",
+		"blockquote" => "blockquote",
+		"button" => "button",
+		"caption" => "caption",
+		"code" => "code",
+		"datalist" => "listbox",
+		"dd" => "definition",
+		"del" | "s" => "deletion",
+		"dfn" | "dt" => "term",
+		"dialog" => "dialog",
+		"em" => "emphasis",
+		"figure" => "figure",
+		"footer" if !is_scoped_to_section(element) => "contentinfo",
+		"form" => "form",
+		"h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+		"header" if !is_scoped_to_section(element) => "banner",
+		"hr" => "separator",
+		"html" => "document",
+		"img" if element.get_attribute("alt").as_deref() == Some("") => "presentation",
+		"img" => "img",
+		"input" => return input_role(element),
+		"ins" => "insertion",
+		"li" => "listitem",
+		"main" => "main",
+		"math" => "math",
+		"menu" | "ol" | "ul" => "list",
+		"meter" => "meter",
+		"nav" => "navigation",
+		"option" => "option",
+		"output" => "status",
+		"p" => "paragraph",
+		"progress" => "progressbar",
+		"search" => "search",
+		"section" if has("aria-label") || has("aria-labelledby") => "region",
+		"select" => {
+			let size = element
+				.get_attribute("size")
+				.and_then(|size| size.parse::<u32>().ok())
+				.unwrap_or(0);
+			if has("multiple") || size > 1 {
+				"listbox"
+			} else {
+				"combobox"
+			}
+		}
+		"strong" => "strong",
+		"sub" => "subscript",
+		"sup" => "superscript",
+		"table" => "table",
+		"tbody" | "tfoot" | "thead" => "rowgroup",
+		"td" => "cell",
+		"textarea" => "textbox",
+		"th" if element.get_attribute("scope").as_deref() == Some("row") => "rowheader",
+		"th" => "columnheader",
+		"time" => "time",
+		"tr" => "row",
+		_ => return None,
+	};
+
+	Some(role)
+}
+
+/// Get the heading level of an element, either from its `aria-level`
+/// attribute or from the tag name for `<h1>` through `<h6>`.
+pub fn heading_level(element: &Element) -> Option<u32> {
+	if let Some(level) = element
+		.get_attribute("aria-level")
+		.and_then(|level| level.parse().ok())
+	{
+		return Some(level);
+	}
+
+	match element.tag_name().to_lowercase().as_str() {
+		"h1" => Some(1),
+		"h2" => Some(2),
+		"h3" => Some(3),
+		"h4" => Some(4),
+		"h5" => Some(5),
+		"h6" => Some(6),
+		_ => None,
+	}
+}
+
+fn input_role(element: &Element) -> Option<&'static str> {
+	let has_list = element.has_attribute("list");
+	let input_type = element
+		.get_attribute("type")
+		.unwrap_or_default()
+		.to_lowercase();
+	let role = match input_type.as_str() {
+		"button" | "image" | "reset" | "submit" => "button",
+		"checkbox" => "checkbox",
+		"radio" => "radio",
+		"range" => "slider",
+		"number" => "spinbutton",
+		"email" | "search" | "tel" | "text" | "url" | "" if has_list => "combobox",
+		"search" => "searchbox",
+		"color" | "date" | "datetime-local" | "file" | "hidden" | "month" | "password" | "time"
+		| "week" => return None,
+		// `text`, `email`, `tel`, `url` and unknown types are all text boxes.
+		_ => "textbox",
+	};
+
+	Some(role)
+}
+
+/// `<header>` and `<footer>` are only landmarks when they aren't nested in a
+/// sectioning element.
+fn is_scoped_to_section(element: &Element) -> bool {
+	element
+		.parent_element()
+		.and_then(|parent| {
+			parent
+				.closest("article, aside, main, nav, section")
+				.ok()
+				.flatten()
+		})
+		.is_some()
+}
+
+/// Returns `true` if the element is excluded from the accessibility tree,
+/// because it or one of its ancestors is `hidden`, `aria-hidden="true"`, or
+/// not displayed, or because it has `visibility: hidden`.
+pub fn is_inaccessible(element: &Element) -> bool {
+	let window = leptos::prelude::window();
+	if window
+		.get_computed_style(element)
+		.ok()
+		.flatten()
+		.and_then(|style| style.get_property_value("visibility").ok())
+		.is_some_and(|visibility| visibility == "hidden")
+	{
+		return true;
+	}
+
+	let mut current = Some(element.clone());
+	while let Some(element) = current {
+		if element.has_attribute("hidden")
+			|| element.get_attribute("aria-hidden").as_deref() == Some("true")
+			|| window
+				.get_computed_style(&element)
+				.ok()
+				.flatten()
+				.and_then(|style| style.get_property_value("display").ok())
+				.is_some_and(|display| display == "none")
+		{
+			return true;
+		}
+		current = element.parent_element();
+	}
+
+	false
+}
+
+/// Options used to narrow down the `get_by_role_with` series of queries.
+///
+/// ```rust
+/// use leptos_testing_library::prelude::*;
+///
+/// let options = ByRoleOptions::default().name("Save").pressed(false);
+/// assert_eq!(options.to_string(), r#"name: "Save", pressed: false"#);
+/// ```
+#[derive(Clone, Default)]
+pub struct ByRoleOptions {
+	name: Option<TextMatch>,
+	level: Option<u32>,
+	checked: Option<bool>,
+	selected: Option<bool>,
+	expanded: Option<bool>,
+	pressed: Option<bool>,
+	hidden: bool,
+}
+
+impl ByRoleOptions {
+	/// Only match elements whose accessible name matches `name`. See
+	/// [`accessible_name`](crate::dom::accessibility::accessible_name).
+	#[must_use]
+	pub fn name<M: Into<TextMatch>>(self, name: M) -> Self {
+		Self {
+			name: Some(name.into()),
+			..self
+		}
+	}
+
+	/// Only match headings of this level, i.e `2` for `<h2>`.
+	#[must_use]
+	pub fn level(self, level: u32) -> Self {
+		Self {
+			level: Some(level),
+			..self
+		}
+	}
+
+	/// Only match checkboxes, radio buttons and switches that are (or
+	/// aren't) checked, either natively or with `aria-checked`.
+	#[must_use]
+	pub fn checked(self, checked: bool) -> Self {
+		Self {
+			checked: Some(checked),
+			..self
+		}
+	}
+
+	/// Only match elements that are (or aren't) selected, either natively or
+	/// with `aria-selected`. Elements without the attribute have no selected
+	/// state and never match.
+	#[must_use]
+	pub fn selected(self, selected: bool) -> Self {
+		Self {
+			selected: Some(selected),
+			..self
+		}
+	}
+
+	/// Only match elements whose `aria-expanded` state is `expanded`. Elements
+	/// without the attribute never match.
+	#[must_use]
+	pub fn expanded(self, expanded: bool) -> Self {
+		Self {
+			expanded: Some(expanded),
+			..self
+		}
+	}
+
+	/// Only match elements whose `aria-pressed` state is `pressed`. Elements
+	/// without the attribute never match.
+	#[must_use]
+	pub fn pressed(self, pressed: bool) -> Self {
+		Self {
+			pressed: Some(pressed),
+			..self
+		}
+	}
+
+	/// Also match elements that are excluded from the accessibility tree. See
+	/// [`is_inaccessible`]. Defaults to `false`.
+	#[must_use]
+	pub fn hidden(self, hidden: bool) -> Self {
+		Self { hidden, ..self }
+	}
+
+	/// Returns `true` if the element passes every filter in these options.
+	/// This doesn't check the role itself.
+	pub(crate) fn matches(&self, element: &Element) -> bool {
+		if self.level.is_some() && self.level != heading_level(element) {
+			return false;
+		}
+
+		for (filter, state) in [
+			(self.checked, checked_state(element)),
+			(self.selected, selected_state(element)),
+			(self.expanded, aria_state(element, "aria-expanded")),
+			(self.pressed, aria_state(element, "aria-pressed")),
+		] {
+			if filter.is_some_and(|filter| state != Some(filter)) {
+				return false;
+			}
+		}
+
+		if !self.hidden && is_inaccessible(element) {
+			return false;
+		}
+
+		self.name
+			.as_ref()
+			.is_none_or(|name| name.matches(&accessible_name(element), element))
+	}
+}
+
+/// The `true` or `false` value of the ARIA state `attribute`, or `None` when
+/// it's missing or has another value like `mixed`.
+fn aria_state(element: &Element, attribute: &str) -> Option<bool> {
+	match element.get_attribute(attribute).as_deref() {
+		Some("true") => Some(true),
+		Some("false") => Some(false),
+		_ => None,
+	}
+}
+
+/// Whether a checkbox, radio button or switch is checked. Other elements
+/// have no checked state.
+fn checked_state(element: &Element) -> Option<bool> {
+	if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+		if matches!(input.type_().as_str(), "checkbox" | "radio") {
+			return Some(input.checked());
+		}
+	}
+	let checkable = element_role(element).is_some_and(|role| {
+		matches!(
+			role.as_str(),
+			"checkbox" | "radio" | "switch" | "menuitemcheckbox" | "menuitemradio"
+		)
+	});
+	if checkable {
+		aria_state(element, "aria-checked")
+	} else {
+		None
+	}
+}
+
+fn selected_state(element: &Element) -> Option<bool> {
+	element
+		.dyn_ref::<web_sys::HtmlOptionElement>()
+		.map(web_sys::HtmlOptionElement::selected)
+		.or_else(|| aria_state(element, "aria-selected"))
+}
+
+impl fmt::Display for ByRoleOptions {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let mut filters = Vec::new();
+		if let Some(name) = &self.name {
+			filters.push(format!("name: {name}"));
+		}
+		if let Some(level) = self.level {
+			filters.push(format!("level: {level}"));
+		}
+		for (label, value) in [
+			("checked", self.checked),
+			("selected", self.selected),
+			("expanded", self.expanded),
+			("pressed", self.pressed),
+		] {
+			if let Some(value) = value {
+				filters.push(format!("{label}: {value}"));
+			}
+		}
+		if self.hidden {
+			filters.push("hidden: true".into());
+		}
+		write!(f, "{}", filters.join(", "))
+	}
+}
+
+impl fmt::Debug for ByRoleOptions {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "ByRoleOptions {{ {self} }}")
+	}
+}
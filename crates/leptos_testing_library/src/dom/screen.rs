@@ -0,0 +1,38 @@
+use leptos::prelude::document;
+
+use super::*;
+
+/// `Screen` runs queries against the whole `document.body` rather than a
+/// single rendered component. Use it for content that is rendered outside the
+/// component's container, like modals, toasts and tooltips rendered through a
+/// Leptos `<Portal/>`. See [`screen`].
+#[derive(Clone, Debug)]
+pub struct Screen(Element);
+
+/// Get a [`Screen`] to query everything in `document.body`.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::prelude::*;
+///
+/// async fn close_modal() {
+/// 	screen().find_by_role("dialog").await.unwrap();
+/// 	screen().get_by_text("Close").unwrap().click();
+/// }
+/// # }
+/// ```
+pub fn screen() -> Screen {
+	Screen(
+		document()
+			.body()
+			.expect("the document to have a body")
+			.into(),
+	)
+}
+
+impl HoldsElement for Screen {
+	fn element(&self) -> ElementWrapper {
+		ElementWrapper(&self.0)
+	}
+}
@@ -0,0 +1,132 @@
+use super::*;
+
+/// Attributes whose value is a space separated list of ids.
+const ID_REFERENCES: &[&str] = &[
+	"for",
+	"form",
+	"list",
+	"headers",
+	"aria-activedescendant",
+	"aria-controls",
+	"aria-describedby",
+	"aria-details",
+	"aria-errormessage",
+	"aria-flowto",
+	"aria-labelledby",
+	"aria-owns",
+];
+
+/// Serialize the element of `holder` for a snapshot, in a form that only
+/// changes when the markup does. This is what [`assert_dom_snapshot!`]
+/// compares.
+///
+/// The output is [`pretty_dom`] without a length limit, so attributes are
+/// sorted, whitespace in text is collapsed, and the comments and hydration
+/// attributes Leptos inserts are left out. Ids that contain a number, which
+/// are usually generated, are replaced with `id-1`, `id-2` and so on, in the
+/// order they appear, as are the references to them in attributes like `for`
+/// and `aria-labelledby`.
+///
+/// [`assert_dom_snapshot!`]: crate::assert_dom_snapshot
+pub fn dom_snapshot(holder: &impl HoldsElement) -> String {
+	let element = holder
+		.element()
+		.0
+		.clone_node_with_deep(true)
+		.expect("the element to be cloned")
+		.unchecked_into::<Element>();
+
+	let mut ids = Vec::new();
+	let elements = element.query_selector_all("*").unwrap();
+	for node in std::iter::once(element.clone().into())
+		.chain((0..elements.length()).filter_map(|index| elements.item(index)))
+	{
+		let element = node.unchecked_ref::<Element>();
+		for name in std::iter::once("id").chain(ID_REFERENCES.iter().copied()) {
+			let Some(value) = element.get_attribute(name) else {
+				continue;
+			};
+			let value = value
+				.split_whitespace()
+				.map(|id| normalize_id(id, &mut ids))
+				.collect::<Vec<_>>()
+				.join(" ");
+			element.set_attribute(name, &value).unwrap();
+		}
+	}
+
+	pretty_dom(
+		&element,
+		&PrettyOptions {
+			max_length: usize::MAX,
+			indent: 2,
+			sort_attributes: true,
+			highlight: false,
+			filter_comments: true,
+		},
+	)
+}
+
+/// Replace `id` with a stable placeholder if it looks generated.
+fn normalize_id(id: &str, ids: &mut Vec<String>) -> String {
+	if !id.chars().any(|char| char.is_ascii_digit()) {
+		return id.to_string();
+	}
+	let index = ids.iter().position(|other| other == id).unwrap_or_else(|| {
+		ids.push(id.to_string());
+		ids.len() - 1
+	});
+	format!("id-{}", index + 1)
+}
+
+/// Assert that the element of a render, [`screen`] or [`TestElement`]
+/// matches an [`insta`] snapshot. The element is serialized with
+/// [`dom_snapshot`], and the arguments follow `insta::assert_snapshot!`:
+///
+/// - `assert_dom_snapshot!(render)` compares against a snapshot file named
+///   after the test.
+/// - `assert_dom_snapshot!("name", render)` names the snapshot file.
+/// - `assert_dom_snapshot!(render, @"...")` compares against an inline
+///   snapshot.
+///
+/// Snapshot files are read from disk, which browsers can't do, so tests that
+/// run with `wasm-bindgen-test` in a browser should use inline snapshots.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::prelude::*;
+/// use leptos_testing_library::assert_dom_snapshot;
+/// use leptos_testing_library::prelude::*;
+///
+/// fn check_greeting() {
+/// 	let render = render_for_test(|| view! { <p class="greeting">"Hello"</p> });
+/// 	assert_dom_snapshot!(render, @r#"
+/// 	<div>
+/// 	  <p class="greeting">
+/// 	    Hello
+/// 	  </p>
+/// 	</div>
+/// 	"#);
+/// }
+/// # }
+/// ```
+///
+/// [`screen`]: crate::dom::screen
+/// [`TestElement`]: crate::dom::TestElement
+/// [`insta`]: https://docs.rs/insta
+#[macro_export]
+macro_rules! assert_dom_snapshot {
+	($holder:expr, @$snapshot:literal $(,)?) => {
+		$crate::__private::insta::assert_snapshot!(
+			$crate::dom::dom_snapshot(&$holder),
+			@$snapshot
+		)
+	};
+	($name:expr, $holder:expr $(,)?) => {
+		$crate::__private::insta::assert_snapshot!($name, $crate::dom::dom_snapshot(&$holder))
+	};
+	($holder:expr $(,)?) => {
+		$crate::__private::insta::assert_snapshot!($crate::dom::dom_snapshot(&$holder))
+	};
+}
@@ -0,0 +1,171 @@
+use super::*;
+
+/// The most "did you mean" suggestions listed in a `NotFound` error.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The text based queries, with the method used in errors.
+const TEXT_QUERIES: &[&str] = &[
+	"by_text",
+	"by_label",
+	"by_placeholder",
+	"by_display_value",
+	"by_alt_text",
+	"by_title",
+	"by_test_id",
+];
+
+/// Suggestions for a `get_by_X` query that didn't find anything in
+/// `container`: elements the same text finds with another query, and close
+/// matches for the text.
+pub(crate) fn suggestions(container: &Element, method: &str, text: &TextMatch) -> Vec<String> {
+	let mut suggestions = Vec::new();
+
+	if TEXT_QUERIES.contains(&method) {
+		for other in TEXT_QUERIES.iter().filter(|other| **other != method) {
+			if let Some(element) = find_with(container, other, text).first() {
+				suggestions.push(found_with(method, other, text, element));
+			}
+		}
+	}
+
+	if method == "by_role_with" {
+		let names = ElementWrapper(container)
+			.get_all_by_role(text.clone())
+			.iter()
+			.map(|element| format!("{:?}", accessible_name(element)))
+			.collect::<Vec<_>>();
+		if !names.is_empty() {
+			suggestions.push(format!(
+				"Elements with the role {text} exist, but none match the options. Their \
+				 accessible names are: {}",
+				names.join(", ")
+			));
+		}
+	}
+
+	if let Some(expected) = text.text() {
+		let close = close_matches(expected, candidates(container, method));
+		if !close.is_empty() {
+			let close = close
+				.iter()
+				.map(|candidate| format!("{candidate:?}"))
+				.collect::<Vec<_>>();
+			suggestions.push(format!("Did you mean {}?", close.join(" or ")));
+		}
+	}
+
+	suggestions
+}
+
+fn find_with(container: &Element, method: &str, text: &TextMatch) -> Vec<TestElement> {
+	let wrapper = ElementWrapper(container);
+	let text = text.clone();
+	match method {
+		"by_text" => wrapper.get_all_by_text(text),
+		"by_label" => wrapper.get_all_by_label(text),
+		"by_placeholder" => wrapper.get_all_by_placeholder(text),
+		"by_display_value" => wrapper.get_all_by_display_value(text),
+		"by_alt_text" => wrapper.get_all_by_alt_text(text),
+		"by_title" => wrapper.get_all_by_title(text),
+		"by_test_id" => wrapper.get_all_by_test_id(text),
+		_ => Vec::new(),
+	}
+}
+
+fn found_with(method: &str, other: &str, text: &TextMatch, element: &Element) -> String {
+	let tag = element.tag_name().to_lowercase();
+	if method == "by_label" && other == "by_text" {
+		format!(
+			"An element with the text {text} exists, but it isn't a <label> and no element refers \
+			 to it with aria-labelledby: <{tag}>. Use get_by_text to find it."
+		)
+	} else {
+		format!("get_{other}({text}) would find the <{tag}> element instead.")
+	}
+}
+
+/// The strings `method` matches against in `container`.
+fn candidates(container: &Element, method: &str) -> Vec<String> {
+	let elements = container.get_elements_by_tag_name("*");
+	let mut candidates = Vec::new();
+	for index in 0..elements.length() {
+		let Some(value) = elements
+			.item(index)
+			.and_then(|element| candidate(&element, method))
+		else {
+			continue;
+		};
+		let value = default_normalizer(&value);
+		if !value.is_empty() && !candidates.contains(&value) {
+			candidates.push(value);
+		}
+	}
+	candidates
+}
+
+/// The string of `element` that `method` matches against.
+pub(crate) fn candidate(element: &Element, method: &str) -> Option<String> {
+	match method {
+		"by_text" => Some(own_text(element)),
+		"by_id" => Some(element.id()),
+		"by_label" if element.tag_name() == "LABEL" => element.text_content(),
+		"by_label" => element.get_attribute("aria-label"),
+		"by_placeholder" => element.get_attribute("placeholder"),
+		"by_display_value" => {
+			element
+				.dyn_ref::<web_sys::HtmlInputElement>()
+				.map(web_sys::HtmlInputElement::value)
+				.or_else(|| {
+					element
+						.dyn_ref::<web_sys::HtmlTextAreaElement>()
+						.map(web_sys::HtmlTextAreaElement::value)
+				})
+				.or_else(|| {
+					element
+						.dyn_ref::<web_sys::HtmlSelectElement>()
+						.map(web_sys::HtmlSelectElement::value)
+				})
+		}
+		"by_role" | "by_role_with" => element_role(element),
+		"by_alt_text" => element.get_attribute("alt"),
+		"by_title" => element.get_attribute("title"),
+		"by_test_id" => element.get_attribute(&get_config().test_id_attribute),
+		_ => None,
+	}
+}
+
+/// The `candidates` that are a few edits away from `expected`, closest
+/// first. Matches that only differ in case are always included.
+fn close_matches(expected: &str, candidates: Vec<String>) -> Vec<String> {
+	let expected_lowercase = expected.to_lowercase();
+	let max_distance = (expected.chars().count() / 3).max(1);
+	let mut matches = candidates
+		.into_iter()
+		.filter(|candidate| candidate != expected)
+		.filter_map(|candidate| {
+			let distance = edit_distance(&expected_lowercase, &candidate.to_lowercase());
+			(distance <= max_distance).then_some((distance, candidate))
+		})
+		.collect::<Vec<_>>();
+	matches.sort_by_key(|(distance, _)| *distance);
+	matches
+		.into_iter()
+		.take(MAX_SUGGESTIONS)
+		.map(|(_, candidate)| candidate)
+		.collect()
+}
+
+/// The Levenshtein distance between `a` and `b`, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b = b.chars().collect::<Vec<_>>();
+	let mut previous = (0..=b.len()).collect::<Vec<_>>();
+	for (i, a_char) in a.chars().enumerate() {
+		let mut current = vec![i + 1];
+		for (j, b_char) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(a_char != *b_char);
+			current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+		}
+		previous = current;
+	}
+	previous[b.len()]
+}
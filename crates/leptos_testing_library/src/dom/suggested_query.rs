@@ -0,0 +1,251 @@
+use std::fmt;
+
+use leptos::prelude::document;
+
+use super::*;
+
+/// The kinds of query, from the most to the least accessible. Prefer the
+/// queries that find elements the way users do: by what a screen reader
+/// announces, or by the text they see.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum QueryKind {
+	/// `get_by_role_with`, with the accessible name of the element.
+	Role,
+	/// `get_by_label`.
+	Label,
+	/// `get_by_placeholder`.
+	Placeholder,
+	/// `get_by_text`.
+	Text,
+	/// `get_by_display_value`.
+	DisplayValue,
+	/// `get_by_alt_text`.
+	AltText,
+	/// `get_by_title`.
+	Title,
+	/// `get_by_test_id`.
+	TestId,
+}
+
+impl QueryKind {
+	/// Every kind, in order of priority.
+	pub const ALL: [Self; 8] = [
+		Self::Role,
+		Self::Label,
+		Self::Placeholder,
+		Self::Text,
+		Self::DisplayValue,
+		Self::AltText,
+		Self::Title,
+		Self::TestId,
+	];
+
+	/// The kind of the query `method` names, as used in errors. `by_id` has
+	/// no kind since it isn't something users see.
+	pub(crate) fn from_method(method: &str) -> Option<Self> {
+		match method {
+			"by_role" | "by_role_with" => Some(Self::Role),
+			_ => Self::ALL.into_iter().find(|kind| kind.method() == method),
+		}
+	}
+
+	fn method(self) -> &'static str {
+		match self {
+			Self::Role => "by_role_with",
+			Self::Label => "by_label",
+			Self::Placeholder => "by_placeholder",
+			Self::Text => "by_text",
+			Self::DisplayValue => "by_display_value",
+			Self::AltText => "by_alt_text",
+			Self::Title => "by_title",
+			Self::TestId => "by_test_id",
+		}
+	}
+}
+
+/// The most accessible query that finds an element, and only that element.
+/// See [`TestElement::suggested_query`].
+///
+/// It prints as the Rust code of the query:
+///
+/// ```rust
+/// use leptos_testing_library::dom::QueryKind;
+/// use leptos_testing_library::dom::SuggestedQuery;
+///
+/// let query = SuggestedQuery {
+/// 	kind: QueryKind::Role,
+/// 	text: "button".into(),
+/// 	name: Some("Save".into()),
+/// };
+/// assert_eq!(
+/// 	query.to_string(),
+/// 	r#"get_by_role_with("button", ByRoleOptions::default().name("Save"))"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SuggestedQuery {
+	/// The kind of query.
+	pub kind: QueryKind,
+	/// The text the query matches, or the role for [`QueryKind::Role`].
+	pub text: String,
+	/// The accessible name passed to `get_by_role_with`, for
+	/// [`QueryKind::Role`].
+	pub name: Option<String>,
+}
+
+impl fmt::Display for SuggestedQuery {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "get_{}({:?}", self.kind.method(), self.text)?;
+		if let Some(name) = &self.name {
+			write!(f, ", ByRoleOptions::default().name({name:?})")?;
+		}
+		write!(f, ")")
+	}
+}
+
+impl TestElement {
+	/// Get the most accessible query that finds this element, and only this
+	/// element, in the document. The queries are tried in the order of
+	/// [`QueryKind`]: role and accessible name, label, placeholder, text,
+	/// display value, alt text, title and test id. Returns `None` if none of
+	/// them find it.
+	///
+	/// ```rust
+	/// # #[cfg(target_arch = "wasm32")]
+	/// # mod hidden_example {
+	/// use leptos_testing_library::prelude::*;
+	///
+	/// fn print_query(render: &impl DomQuery) {
+	/// 	let input = render.get_by_test_id("email").unwrap();
+	/// 	if let Some(query) = input.suggested_query() {
+	/// 		// i.e `get_by_role_with("textbox", ByRoleOptions::default().name("Email"))`
+	/// 		leptos::logging::log!("{query}");
+	/// 	}
+	/// }
+	/// # }
+	/// ```
+	pub fn suggested_query(&self) -> Option<SuggestedQuery> {
+		let body = document().body().expect("the document to have a body");
+		suggested_query_in(&body, &self.0)
+	}
+}
+
+/// The most accessible query that finds `element`, and only it, in
+/// `container`.
+pub(crate) fn suggested_query_in(container: &Element, element: &Element) -> Option<SuggestedQuery> {
+	QueryKind::ALL
+		.into_iter()
+		.find_map(|kind| query_for(container, element, kind))
+}
+
+fn query_for(container: &Element, element: &Element, kind: QueryKind) -> Option<SuggestedQuery> {
+	let text = match kind {
+		QueryKind::Role => element_role(element)?,
+		QueryKind::Label => accessible_name(element),
+		_ => default_normalizer(&candidate(element, kind.method())?),
+	};
+	let name = (kind == QueryKind::Role).then(|| accessible_name(element));
+	if text.is_empty() || name.as_ref().is_some_and(String::is_empty) {
+		return None;
+	}
+
+	let wrapper = ElementWrapper(container);
+	let found = match kind {
+		QueryKind::Role => {
+			let options = ByRoleOptions::default().name(name.clone()?);
+			wrapper.get_all_by_role_with(text.clone(), options)
+		}
+		QueryKind::Label => wrapper.get_all_by_label(text.clone()),
+		QueryKind::Placeholder => wrapper.get_all_by_placeholder(text.clone()),
+		QueryKind::Text => wrapper.get_all_by_text(text.clone()),
+		QueryKind::DisplayValue => wrapper.get_all_by_display_value(text.clone()),
+		QueryKind::AltText => wrapper.get_all_by_alt_text(text.clone()),
+		QueryKind::Title => wrapper.get_all_by_title(text.clone()),
+		QueryKind::TestId => wrapper.get_all_by_test_id(text.clone()),
+	};
+	let unique = matches!(found.as_slice(), [only] if AsRef::<Element>::as_ref(&only.0) == element);
+
+	unique.then_some(SuggestedQuery { kind, text, name })
+}
+
+/// Warn when `element`, found with `method`, could have been found with a
+/// more accessible query. Only when [`Config::warn_on_lower_priority_queries`]
+/// is set.
+pub(crate) fn warn_on_lower_priority_query(
+	container: &Element,
+	element: &Element,
+	method: &str,
+	ident: &str,
+) {
+	if !get_config().warn_on_lower_priority_queries {
+		return;
+	}
+	if let Some(warning) = lower_priority_query_warning_in(container, element, method, ident) {
+		#[cfg(test)]
+		LOGGED_WARNINGS.with_borrow_mut(|warnings| warnings.push(warning.clone()));
+		leptos::logging::warn!("{warning}");
+	}
+}
+
+#[cfg(test)]
+thread_local! {
+	/// The warnings logged by [`warn_on_lower_priority_query`], for the tests.
+	static LOGGED_WARNINGS: std::cell::RefCell<Vec<String>> = const {
+		std::cell::RefCell::new(Vec::new())
+	};
+}
+
+/// The warning for `element`, found with `method` in `container`, if a more
+/// accessible query finds it too.
+fn lower_priority_query_warning_in(
+	container: &Element,
+	element: &Element,
+	method: &str,
+	ident: &str,
+) -> Option<String> {
+	let suggested = suggested_query_in(container, element)?;
+	QueryKind::from_method(method)
+		.is_none_or(|kind| suggested.kind < kind)
+		.then(|| {
+			format!(
+				"get_{method}({ident}) found the element, but a more accessible query finds it \
+				 too: {suggested}"
+			)
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use wasm_bindgen_test::*;
+
+	use super::*;
+
+	wasm_bindgen_test_configure!(run_in_browser);
+
+	#[wasm_bindgen_test]
+	fn warns_on_lower_priority_queries() {
+		let container = document().create_element("div").unwrap();
+		document().body().unwrap().append_child(&container).unwrap();
+		container.set_inner_html(
+			r#"<button data-testid="save">Save</button>
+			<span data-testid="first">Item</span>
+			<span>Item</span>"#,
+		);
+		let render = ElementWrapper(&container);
+
+		let config = get_config();
+		configure(|config| config.warn_on_lower_priority_queries = true);
+		LOGGED_WARNINGS.take();
+		render.get_by_test_id("save").unwrap();
+		render.get_by_role("button").unwrap();
+		render.get_by_test_id("first").unwrap();
+		let warnings = LOGGED_WARNINGS.take();
+		configure(|current| *current = config);
+		container.remove();
+
+		assert_eq!(warnings, vec![
+			r#"get_by_test_id("save") found the element, but a more accessible query finds it too: get_by_role_with("button", ByRoleOptions::default().name("Save"))"#
+				.to_string()
+		]);
+	}
+}
@@ -26,7 +26,21 @@ impl TestElement {
 	/// Gets the text the user would see. There might be multiple nodes, this
 	/// will display all text
 	pub fn display_text(&self) -> String {
-		get_all_text_nodes(self.dyn_ref::<Node>().unwrap()).join_text(String::new())
+		get_all_text_nodes(self.dyn_ref::<Node>().unwrap()).join_text("")
+	}
+
+	/// Gets the accessible name of the element, the text a screen reader
+	/// announces for it. See
+	/// [`accessible_name`](crate::dom::accessibility::accessible_name).
+	pub fn accessible_name(&self) -> String {
+		accessible_name(&self.0)
+	}
+
+	/// Gets the accessible description of the element, the extra text a
+	/// screen reader announces after its name. See
+	/// [`accessible_description`](crate::dom::accessibility::accessible_description).
+	pub fn accessible_description(&self) -> String {
+		accessible_description(&self.0)
 	}
 
 	/// Tries to parse the text context of an element
@@ -38,7 +52,8 @@ impl TestElement {
 	}
 
 	/// If the element is a Input, `TextArea` or Select Element this will set
-	/// the display value. Otherwise it will panic.
+	/// the display value and fire `input` and `change` events, so `on:input`
+	/// and `on:change` handlers run. Otherwise it will panic.
 	pub fn set_display_value<S: AsRef<str>>(&self, value: S) {
 		if let Some(item) = self.dyn_ref::<web_sys::HtmlTextAreaElement>() {
 			item.set_value(value.as_ref());
@@ -52,5 +67,35 @@ impl TestElement {
              If you want to set a non-display value just use .set_value() transparently."
 			)
 		}
+
+		fire_event::input(self);
+		fire_event::change(self);
 	}
 }
+
+/// `TestElement` implements `DomQuery` so queries can be chained, each one
+/// scoped to the element found by the previous one.
+impl HoldsElement for TestElement {
+	fn element(&self) -> ElementWrapper {
+		ElementWrapper(&self.0)
+	}
+}
+
+/// Scope queries to the inside of `element`. This is the same as querying
+/// `element` directly, but reads better when a test narrows down to a part of
+/// the page.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::prelude::*;
+///
+/// fn confirm(render: &impl DomQuery) {
+/// 	let dialog = render.get_by_role("dialog").unwrap();
+/// 	within(&dialog).get_by_text("Confirm").unwrap().click();
+/// }
+/// # }
+/// ```
+pub fn within(element: &TestElement) -> ElementWrapper<'_> {
+	ElementWrapper(&element.0)
+}
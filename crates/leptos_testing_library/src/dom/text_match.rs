@@ -0,0 +1,224 @@
+use std::fmt;
+use std::rc::Rc;
+
+use regex::Regex;
+
+use super::*;
+
+/// A function used to normalize text before it is matched. See
+/// [`Config::normalizer`] and [`default_normalizer`].
+pub type Normalizer = Rc<dyn Fn(&str) -> String>;
+
+type Predicate = Rc<dyn Fn(&str, &Element) -> bool>;
+
+/// Trims the text and collapses every run of whitespace into a single space.
+/// This is the default [`Config::normalizer`].
+///
+/// ```rust
+/// use leptos_testing_library::dom::default_normalizer;
+///
+/// assert_eq!(default_normalizer("  Hello \n  world "), "Hello world");
+/// ```
+pub fn default_normalizer(text: &str) -> String {
+	text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `TextMatch` describes how the text of an element is matched by every
+/// `DomQuery` method. Strings match exactly, regular expressions match
+/// anywhere in the text, and closures receive the text and the element and
+/// decide for themselves.
+///
+/// Before matching, the text is normalized with the [`Config::normalizer`],
+/// which trims it and collapses whitespace by default.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::prelude::*;
+/// use regex::Regex;
+///
+/// fn find_save_buttons(render: &impl DomQuery) {
+/// 	// Strings match exactly.
+/// 	render.get_by_text("Save").unwrap();
+/// 	// Exact and contained text can ignore case.
+/// 	render
+/// 		.get_by_text(TextMatch::exact("save").ignore_case())
+/// 		.unwrap();
+/// 	render.get_by_text(TextMatch::contains("Sav")).unwrap();
+/// 	// Regular expressions match anywhere in the text.
+/// 	render.get_by_text(Regex::new(r"^Sa.e$").unwrap()).unwrap();
+/// 	// Closures receive the normalized text and the element.
+/// 	render
+/// 		.get_by_text(|text: &str, element: &web_sys::Element| {
+/// 			text == "Save" && element.tag_name() == "BUTTON"
+/// 		})
+/// 		.unwrap();
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TextMatch {
+	kind: TextMatchKind,
+	ignore_case: bool,
+	normalizer: Option<Normalizer>,
+}
+
+#[derive(Clone)]
+enum TextMatchKind {
+	Exact(String),
+	Contains(String),
+	Regex(Regex),
+	Predicate(Predicate),
+}
+
+impl TextMatch {
+	/// Match text that is exactly equal to `text`.
+	pub fn exact<S: AsRef<str>>(text: S) -> Self {
+		Self::new(TextMatchKind::Exact(text.as_ref().to_string()))
+	}
+
+	/// Match text that contains `text`, i.e "abc" contains "a".
+	pub fn contains<S: AsRef<str>>(text: S) -> Self {
+		Self::new(TextMatchKind::Contains(text.as_ref().to_string()))
+	}
+
+	/// Match text that the regular expression matches anywhere in.
+	pub fn regex(regex: Regex) -> Self {
+		Self::new(TextMatchKind::Regex(regex))
+	}
+
+	/// Match with a custom function which receives the normalized text and
+	/// the element it belongs to.
+	pub fn predicate<F>(predicate: F) -> Self
+	where
+		F: Fn(&str, &Element) -> bool + 'static,
+	{
+		Self::new(TextMatchKind::Predicate(Rc::new(predicate)))
+	}
+
+	fn new(kind: TextMatchKind) -> Self {
+		Self {
+			kind,
+			ignore_case: false,
+			normalizer: None,
+		}
+	}
+
+	/// Ignore case when matching exact or contained text. Has no effect on
+	/// regular expressions, use the `(?i)` flag instead, or predicates.
+	#[must_use]
+	pub fn ignore_case(self) -> Self {
+		Self {
+			ignore_case: true,
+			..self
+		}
+	}
+
+	/// Use `normalizer` instead of the [`Config::normalizer`] for this match.
+	#[must_use]
+	pub fn normalizer<F>(self, normalizer: F) -> Self
+	where
+		F: Fn(&str) -> String + 'static,
+	{
+		Self {
+			normalizer: Some(Rc::new(normalizer)),
+			..self
+		}
+	}
+
+	/// Returns `true` if the normalized `text` of `element` matches.
+	pub fn matches(&self, text: &str, element: &Element) -> bool {
+		let text = self.normalize(text);
+		match &self.kind {
+			TextMatchKind::Predicate(predicate) => predicate(&text, element),
+			_ => self.matches_normalized(&text),
+		}
+	}
+
+	/// The text this matches against, unless it is a regular expression or a
+	/// predicate.
+	pub(crate) fn text(&self) -> Option<&str> {
+		match &self.kind {
+			TextMatchKind::Exact(text) | TextMatchKind::Contains(text) => Some(text),
+			TextMatchKind::Regex(_) | TextMatchKind::Predicate(_) => None,
+		}
+	}
+
+	fn matches_normalized(&self, text: &str) -> bool {
+		match &self.kind {
+			TextMatchKind::Exact(expected) if self.ignore_case => {
+				text.to_lowercase() == expected.to_lowercase()
+			}
+			TextMatchKind::Exact(expected) => text == expected,
+			TextMatchKind::Contains(expected) if self.ignore_case => {
+				text.to_lowercase().contains(&expected.to_lowercase())
+			}
+			TextMatchKind::Contains(expected) => text.contains(expected.as_str()),
+			TextMatchKind::Regex(regex) => regex.is_match(text),
+			TextMatchKind::Predicate(_) => false,
+		}
+	}
+
+	fn normalize(&self, text: &str) -> String {
+		match &self.normalizer {
+			Some(normalizer) => normalizer(text),
+			None => (get_config().normalizer)(text),
+		}
+	}
+}
+
+impl fmt::Display for TextMatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.kind {
+			TextMatchKind::Exact(text) => write!(f, "{text:?}")?,
+			TextMatchKind::Contains(text) => write!(f, "text containing {text:?}")?,
+			TextMatchKind::Regex(regex) => write!(f, "/{regex}/")?,
+			TextMatchKind::Predicate(_) => write!(f, "<predicate>")?,
+		}
+
+		if self.ignore_case {
+			write!(f, " (ignoring case)")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Debug for TextMatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "TextMatch({self})")
+	}
+}
+
+impl From<&str> for TextMatch {
+	fn from(text: &str) -> Self {
+		Self::exact(text)
+	}
+}
+
+impl From<String> for TextMatch {
+	fn from(text: String) -> Self {
+		Self::exact(text)
+	}
+}
+
+impl From<&String> for TextMatch {
+	fn from(text: &String) -> Self {
+		Self::exact(text)
+	}
+}
+
+impl From<Regex> for TextMatch {
+	fn from(regex: Regex) -> Self {
+		Self::regex(regex)
+	}
+}
+
+impl<F> From<F> for TextMatch
+where
+	F: Fn(&str, &Element) -> bool + 'static,
+{
+	fn from(predicate: F) -> Self {
+		Self::predicate(predicate)
+	}
+}
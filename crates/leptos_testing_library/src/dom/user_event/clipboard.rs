@@ -0,0 +1,127 @@
+use super::*;
+
+thread_local! {
+	/// The text on the stubbed clipboard.
+	static CLIPBOARD: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Get the text on the clipboard. The clipboard is stubbed, so this is only
+/// the text copied by [`copy`], [`cut`] or [`write_clipboard`].
+pub fn read_clipboard() -> String {
+	CLIPBOARD.with_borrow(Clone::clone)
+}
+
+/// Replace the text on the clipboard, without firing any events.
+pub fn write_clipboard(text: &str) {
+	CLIPBOARD.with_borrow_mut(|clipboard| text.clone_into(clipboard));
+}
+
+/// Copy the selected text of the focused element, or of the document, to the
+/// clipboard. This fires a `copy` event, and when a handler cancels it the
+/// data it set on `clipboardData` is copied instead of the selection.
+/// Returns the copied text.
+pub fn copy() -> String {
+	let (text, _) = copy_selection(fire_event::copy_with);
+	text
+}
+
+/// Cut the selected text of the focused element to the clipboard. This works
+/// like [`copy`] with a `cut` event, then deletes the selection from the
+/// element when it is editable and the event wasn't cancelled. Returns the
+/// copied text.
+pub fn cut() -> String {
+	let (text, cancelled) = copy_selection(fire_event::cut_with);
+	if !cancelled {
+		if let Some(editable) = Editable::new(&active_element()) {
+			let (start, end) = editable.selection();
+			if start != end {
+				editable.insert("", "deleteByCut");
+			}
+		}
+	}
+	text
+}
+
+/// Paste `text` into the focused element. This fires a `paste` event and, if
+/// it isn't cancelled, inserts the text into the element.
+pub fn paste(text: &str) {
+	let target = active_element();
+	let cancelled = fire_event::paste_with(&target, clipboard_options(text));
+
+	if !cancelled {
+		if let Some(editable) = Editable::new(&target) {
+			editable.insert(text, "insertFromPaste");
+		}
+	}
+}
+
+/// Paste the text on the clipboard into the focused element, like
+/// [`paste`].
+pub fn paste_from_clipboard() {
+	paste(&read_clipboard());
+}
+
+fn clipboard_options(text: &str) -> EventOptions {
+	let data_transfer = web_sys::DataTransfer::new().unwrap();
+	_ = data_transfer.set_data("text/plain", text);
+	EventOptions {
+		data_transfer: Some(data_transfer),
+		..Default::default()
+	}
+}
+
+/// Fire a clipboard event on the focused element and copy the selection, or
+/// the data set by the handler that cancelled the event. Returns the copied
+/// text and whether the event was cancelled.
+fn copy_selection(fire: fn(&Element, EventOptions) -> bool) -> (String, bool) {
+	let target = active_element();
+	let options = clipboard_options("");
+	let data_transfer = options.data_transfer.clone().unwrap();
+	let cancelled = fire(&target, options);
+	let text = if cancelled {
+		data_transfer.get_data("text/plain").unwrap_or_default()
+	} else {
+		selected_text(&target)
+	};
+
+	write_clipboard(&text);
+	(text, cancelled)
+}
+
+/// The selected text of an `<input>` or `<textarea>`, or the text selected in
+/// the document for other elements.
+fn selected_text(element: &Element) -> String {
+	let field = if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+		input
+			.selection_start()
+			.ok()
+			.flatten()
+			.zip(input.selection_end().ok().flatten())
+			.map(|range| (input.value(), range))
+	} else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+		textarea
+			.selection_start()
+			.ok()
+			.flatten()
+			.zip(textarea.selection_end().ok().flatten())
+			.map(|range| (textarea.value(), range))
+	} else {
+		None
+	};
+
+	match field {
+		Some((value, (start, end))) => {
+			let value = value.encode_utf16().collect::<Vec<_>>();
+			let (start, end) = (start as usize, (end as usize).min(value.len()));
+			String::from_utf16_lossy(&value[start.min(end)..end])
+		}
+		None => {
+			window()
+				.get_selection()
+				.ok()
+				.flatten()
+				.map(|selection| String::from(selection.to_string()))
+				.unwrap_or_default()
+		}
+	}
+}
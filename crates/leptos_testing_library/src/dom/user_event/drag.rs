@@ -0,0 +1,70 @@
+use super::*;
+
+/// Drag `source` and drop it onto `target`, as a user would with a mouse.
+///
+/// This presses the main button on `source`, fires `dragstart` and `drag` on
+/// it, then `dragenter` and `dragover` on `target`. When `dragover` is
+/// cancelled, which is how a drop zone accepts a drop, `drop` is fired on
+/// `target`, otherwise `dragleave` is. The drag ends with `dragend` on
+/// `source`. Every event shares the same `DataTransfer`, so data set in a
+/// `dragstart` handler can be read in the `drop` handler. Nothing is dragged
+/// when `dragstart` is cancelled.
+///
+/// Returns a `PointerEventsDisabled` error if either element has
+/// `pointer-events: none`.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::prelude::*;
+///
+/// fn move_card(render: &impl DomQuery) {
+/// 	let card = render.get_by_text("Write tests").unwrap();
+/// 	let column = render.get_by_label("Done").unwrap();
+/// 	user_event::drag_and_drop(&card, &column).unwrap();
+/// }
+/// # }
+/// ```
+pub fn drag_and_drop(source: &Element, target: &Element) -> Result<(), TestingLibraryError> {
+	check_pointer_events(source)?;
+	check_pointer_events(target)?;
+
+	move_pointer(source);
+	fire_event::pointer_down_with(source, pointer_options(1, 1));
+	if !is_disabled(source) {
+		fire_event::mouse_down_with(
+			source,
+			EventOptions {
+				buttons: 1,
+				detail: 1,
+				..Default::default()
+			},
+		);
+	}
+
+	let data_transfer = web_sys::DataTransfer::new().unwrap();
+	let options = |buttons| {
+		EventOptions {
+			buttons,
+			data_transfer: Some(data_transfer.clone()),
+			..Default::default()
+		}
+	};
+
+	if fire_event::drag_start_with(source, options(1)) {
+		return Ok(());
+	}
+	fire_event::drag_with(source, options(1));
+	fire_event::drag_enter_with(target, options(1));
+	if fire_event::drag_over_with(target, options(1)) {
+		fire_event::drop_with(target, options(0));
+	} else {
+		fire_event::drag_leave_with(target, options(0));
+	}
+	fire_event::drag_end_with(source, options(0));
+
+	// The browser doesn't fire pointer events during a drag, the pointer is
+	// just over the target once it ends.
+	POINTER_TARGET.with_borrow_mut(|pointer_target| *pointer_target = Some(target.clone()));
+	Ok(())
+}
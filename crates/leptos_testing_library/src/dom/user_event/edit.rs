@@ -0,0 +1,190 @@
+use super::*;
+
+/// An element whose value can be edited by typing into it.
+pub(crate) enum Editable {
+	Input(web_sys::HtmlInputElement),
+	TextArea(web_sys::HtmlTextAreaElement),
+	Content(HtmlElement),
+}
+
+impl Editable {
+	/// Get the editable element, or `None` if `element` can't be typed into
+	/// because of its type or because it is disabled or read only.
+	pub(crate) fn new(element: &Element) -> Option<Self> {
+		if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+			let is_text = !matches!(
+				input.type_().as_str(),
+				"button"
+					| "checkbox" | "color"
+					| "file" | "hidden"
+					| "image" | "radio"
+					| "range" | "reset"
+					| "submit"
+			);
+			(is_text && !input.disabled() && !input.read_only()).then(|| Self::Input(input.clone()))
+		} else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+			(!textarea.disabled() && !textarea.read_only())
+				.then(|| Self::TextArea(textarea.clone()))
+		} else {
+			element
+				.dyn_ref::<HtmlElement>()
+				.filter(|element| element.is_content_editable())
+				.map(|element| Self::Content(element.clone()))
+		}
+	}
+
+	fn element(&self) -> &Element {
+		match self {
+			Self::Input(input) => input,
+			Self::TextArea(textarea) => textarea,
+			Self::Content(element) => element,
+		}
+	}
+
+	fn value(&self) -> String {
+		match self {
+			Self::Input(input) => input.value(),
+			Self::TextArea(textarea) => textarea.value(),
+			Self::Content(element) => element.text_content().unwrap_or_default(),
+		}
+	}
+
+	fn set_value(&self, value: &str) {
+		match self {
+			Self::Input(input) => input.set_value(value),
+			Self::TextArea(textarea) => textarea.set_value(value),
+			Self::Content(element) => element.set_text_content(Some(value)),
+		}
+	}
+
+	/// The length of the value, in UTF-16 code units.
+	pub(crate) fn length(&self) -> usize {
+		self.value().encode_utf16().count()
+	}
+
+	fn max_length(&self) -> Option<usize> {
+		let max_length = match self {
+			Self::Input(input) => input.max_length(),
+			Self::TextArea(textarea) => textarea.max_length(),
+			Self::Content(_) => -1,
+		};
+		usize::try_from(max_length).ok()
+	}
+
+	/// The selected range, in UTF-16 code units. Inputs that don't support
+	/// selection, like `type="email"`, and content editable elements always
+	/// have the cursor at the end.
+	pub(crate) fn selection(&self) -> (usize, usize) {
+		let range = match self {
+			Self::Input(input) => {
+				input
+					.selection_start()
+					.ok()
+					.flatten()
+					.zip(input.selection_end().ok().flatten())
+			}
+			Self::TextArea(textarea) => {
+				textarea
+					.selection_start()
+					.ok()
+					.flatten()
+					.zip(textarea.selection_end().ok().flatten())
+			}
+			Self::Content(_) => None,
+		};
+
+		range.map_or_else(
+			|| {
+				let end = self.length();
+				(end, end)
+			},
+			|(start, end)| (start as usize, end as usize),
+		)
+	}
+
+	pub(crate) fn set_selection(&self, start: usize, end: usize) {
+		let (start, end) = (start as u32, end as u32);
+		match self {
+			Self::Input(input) => _ = input.set_selection_range(start, end),
+			Self::TextArea(textarea) => _ = textarea.set_selection_range(start, end),
+			Self::Content(_) => {}
+		}
+	}
+
+	/// Select the whole value.
+	pub(crate) fn select_all(&self) {
+		self.set_selection(0, self.length());
+	}
+
+	/// Move the cursor to the end of the value.
+	pub(crate) fn move_to_end(&self) {
+		let end = self.length();
+		self.set_selection(end, end);
+	}
+
+	/// Insert `text` in place of the selection, as if it was typed.
+	pub(crate) fn insert(&self, text: &str, input_type: &str) {
+		let range = self.selection();
+		self.edit(text, range, input_type);
+	}
+
+	/// Delete the selection, or the character before (or after when
+	/// `forward`) the cursor when nothing is selected.
+	pub(crate) fn delete(&self, forward: bool) {
+		let (start, end) = self.selection();
+		let length = self.length();
+		let input_type = if forward {
+			"deleteContentForward"
+		} else {
+			"deleteContentBackward"
+		};
+		let range = if start != end {
+			(start, end)
+		} else if forward && end < length {
+			(start, end + 1)
+		} else if !forward && start > 0 {
+			(start - 1, end)
+		} else {
+			return;
+		};
+		self.edit("", range, input_type);
+	}
+
+	/// Replace the UTF-16 `range` of the value with `text`, firing
+	/// `beforeinput` and `input` events. Nothing changes if `beforeinput` is
+	/// cancelled or the value would exceed the `maxlength`.
+	fn edit(&self, text: &str, (start, end): (usize, usize), input_type: &str) {
+		let data = (!text.is_empty()).then(|| text.to_string());
+		let options = || {
+			EventOptions {
+				data: data.clone(),
+				input_type: input_type.to_string(),
+				..Default::default()
+			}
+		};
+
+		if fire_event::before_input_with(self.element(), options()) {
+			return;
+		}
+
+		let value = self.value().encode_utf16().collect::<Vec<_>>();
+		let (start, end) = (start.min(value.len()), end.min(value.len()));
+		let inserted = text.encode_utf16().collect::<Vec<_>>();
+		let mut next = value[..start].to_vec();
+		next.extend(&inserted);
+		next.extend(&value[end..]);
+
+		if !inserted.is_empty()
+			&& self
+				.max_length()
+				.is_some_and(|max_length| next.len() > max_length)
+		{
+			return;
+		}
+
+		self.set_value(&String::from_utf16_lossy(&next));
+		let cursor = start + inserted.len();
+		self.set_selection(cursor, cursor);
+		fire_event::input_with(self.element(), options());
+	}
+}
@@ -0,0 +1,454 @@
+use super::*;
+
+/// A key on the keyboard.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct KeyDef {
+	/// The `key` of the keyboard events, i.e `"a"` or `"Enter"`.
+	pub(crate) key: String,
+	/// The `code` of the keyboard events, i.e `"KeyA"` or `"Enter"`.
+	pub(crate) code: String,
+	/// The legacy `keyCode` of the keyboard events.
+	pub(crate) key_code: u32,
+}
+
+impl KeyDef {
+	/// The key for `{name}`, i.e `{Enter}`, or for a single character.
+	fn from_name(name: &str) -> Self {
+		let mut chars = name.chars();
+		if let (Some(char), None) = (chars.next(), chars.next()) {
+			return Self::from_char(char);
+		}
+
+		let (code, key_code) = match name {
+			"Alt" => ("AltLeft", 18),
+			"ArrowDown" => ("ArrowDown", 40),
+			"ArrowLeft" => ("ArrowLeft", 37),
+			"ArrowRight" => ("ArrowRight", 39),
+			"ArrowUp" => ("ArrowUp", 38),
+			"Backspace" => ("Backspace", 8),
+			"CapsLock" => ("CapsLock", 20),
+			"Control" => ("ControlLeft", 17),
+			"Delete" => ("Delete", 46),
+			"End" => ("End", 35),
+			"Enter" => ("Enter", 13),
+			"Escape" => ("Escape", 27),
+			"Home" => ("Home", 36),
+			"Meta" => ("MetaLeft", 91),
+			"PageDown" => ("PageDown", 34),
+			"PageUp" => ("PageUp", 33),
+			"Shift" => ("ShiftLeft", 16),
+			"Space" => return Self::from_char(' '),
+			"Tab" => ("Tab", 9),
+			_ => ("Unknown", 0),
+		};
+
+		Self {
+			key: name.to_string(),
+			code: code.to_string(),
+			key_code,
+		}
+	}
+
+	/// The key for `[code]`, i.e `[KeyA]` or `[ShiftLeft]`.
+	fn from_code(code: &str) -> Self {
+		let key = if let Some(letter) = code.strip_prefix("Key").filter(|rest| rest.len() == 1) {
+			letter.to_lowercase()
+		} else if let Some(digit) = code.strip_prefix("Digit").filter(|rest| rest.len() == 1) {
+			digit.to_string()
+		} else {
+			code.trim_end_matches("Left")
+				.trim_end_matches("Right")
+				.to_string()
+		};
+
+		Self {
+			code: code.to_string(),
+			..Self::from_name(&key)
+		}
+	}
+
+	fn from_char(char: char) -> Self {
+		let (code, key_code) = match char {
+			'a'..='z' | 'A'..='Z' => {
+				(
+					format!("Key{}", char.to_ascii_uppercase()),
+					u32::from(char.to_ascii_uppercase()),
+				)
+			}
+			'0'..='9' => (format!("Digit{char}"), u32::from(char)),
+			' ' => ("Space".into(), 32),
+			'\n' => return Self::from_name("Enter"),
+			'\t' => return Self::from_name("Tab"),
+			_ => {
+				let code = match char {
+					'`' => "Backquote",
+					'-' => "Minus",
+					'=' => "Equal",
+					'[' => "BracketLeft",
+					']' => "BracketRight",
+					'\\' => "Backslash",
+					';' => "Semicolon",
+					'\'' => "Quote",
+					',' => "Comma",
+					'.' => "Period",
+					'/' => "Slash",
+					_ => "Unknown",
+				};
+				(code.into(), 0)
+			}
+		};
+
+		Self {
+			key: char.to_string(),
+			code,
+			key_code,
+		}
+	}
+
+	/// Returns `true` if pressing the key types a character.
+	pub(crate) fn is_printable(&self) -> bool {
+		self.key.chars().count() == 1
+	}
+}
+
+/// A single key descriptor parsed from the input of `keyboard`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct KeyAction {
+	pub(crate) key: KeyDef,
+	/// How many times the key is pressed, `0` when it is only released.
+	pub(crate) presses: u32,
+	/// Whether the key is released afterwards.
+	pub(crate) release: bool,
+}
+
+/// Parse the input of `keyboard` into a list of key actions. Each character
+/// is pressed and released. `{Name}` presses a key by its `key`, and `[Code]`
+/// by its `code`. Within a descriptor, `{Shift>}` holds the key down, `{a>3}`
+/// presses it three times and holds it, `{a>3/}` presses it three times and
+/// releases it, and `{/Shift}` releases it. `{{` and `[[` type a literal `{`
+/// and `[`.
+pub(crate) fn parse_keyboard(input: &str) -> Result<Vec<KeyAction>, String> {
+	let mut actions = Vec::new();
+	let mut chars = input.chars().peekable();
+
+	while let Some(char) = chars.next() {
+		let closing = match char {
+			'{' => '}',
+			'[' => ']',
+			_ => {
+				actions.push(KeyAction {
+					key: KeyDef::from_char(char),
+					presses: 1,
+					release: true,
+				});
+				continue;
+			}
+		};
+
+		if chars.peek() == Some(&char) {
+			chars.next();
+			actions.push(KeyAction {
+				key: KeyDef::from_char(char),
+				presses: 1,
+				release: true,
+			});
+			continue;
+		}
+
+		let mut descriptor = String::new();
+		loop {
+			match chars.next() {
+				Some(next) if next == closing => break,
+				Some(next) => descriptor.push(next),
+				None => return Err(format!("expected a closing `{closing}`")),
+			}
+		}
+
+		actions.push(parse_descriptor(&descriptor, char == '[')?);
+	}
+
+	Ok(actions)
+}
+
+fn parse_descriptor(descriptor: &str, is_code: bool) -> Result<KeyAction, String> {
+	let (release_only, descriptor) = match descriptor.strip_prefix('/') {
+		Some(rest) => (true, rest),
+		None => (false, descriptor),
+	};
+	let (name, modifier) = match descriptor.split_once('>') {
+		Some((name, modifier)) => (name, Some(modifier)),
+		None => (descriptor, None),
+	};
+
+	if name.is_empty() {
+		return Err(format!("expected a key name in `{descriptor}`"));
+	}
+
+	let key = if is_code {
+		KeyDef::from_code(name)
+	} else {
+		KeyDef::from_name(name)
+	};
+
+	if release_only {
+		if modifier.is_some() {
+			return Err(format!("cannot both hold and release `{name}`"));
+		}
+		return Ok(KeyAction {
+			key,
+			presses: 0,
+			release: true,
+		});
+	}
+
+	let Some(modifier) = modifier else {
+		return Ok(KeyAction {
+			key,
+			presses: 1,
+			release: true,
+		});
+	};
+
+	let (count, release) = match modifier.strip_suffix('/') {
+		Some(count) => (count, true),
+		None => (modifier, false),
+	};
+	let presses = if count.is_empty() {
+		1
+	} else {
+		count
+			.parse()
+			.map_err(|_| format!("expected a number of presses in `{modifier}`"))?
+	};
+
+	Ok(KeyAction {
+		key,
+		presses,
+		release,
+	})
+}
+
+/// The keys held down while `keyboard` runs.
+#[derive(Default)]
+pub(crate) struct Keyboard {
+	held: Vec<KeyDef>,
+}
+
+impl Keyboard {
+	pub(crate) fn run(&mut self, actions: Vec<KeyAction>) {
+		for action in actions {
+			for press in 0..action.presses {
+				self.press(&action.key, press > 0);
+			}
+
+			if action.release {
+				self.release(&action.key);
+			} else if action.presses > 0 && !self.held.contains(&action.key) {
+				self.held.push(action.key);
+			}
+		}
+	}
+
+	fn is_held(&self, key: &str) -> bool {
+		self.held.iter().any(|held| held.key == key)
+	}
+
+	/// The options of the events for `key`. Modifier keys count as pressed
+	/// in their own `keydown` but not their `keyup`.
+	fn options(&self, key: &KeyDef, repeat: bool, is_down: bool) -> EventOptions {
+		let is_modifier = |name: &str| (is_down && key.key == name) || self.is_held(name);
+		EventOptions {
+			key: key.key.clone(),
+			code: key.code.clone(),
+			key_code: key.key_code,
+			repeat,
+			alt_key: is_modifier("Alt"),
+			ctrl_key: is_modifier("Control"),
+			meta_key: is_modifier("Meta"),
+			shift_key: is_modifier("Shift"),
+			..Default::default()
+		}
+	}
+
+	fn press(&self, key: &KeyDef, repeat: bool) {
+		let target = active_element();
+		if fire_event::key_down_with(&target, self.options(key, repeat, true)) {
+			return;
+		}
+
+		let editable = Editable::new(&target);
+		let is_shortcut = self.is_held("Control") || self.is_held("Alt") || self.is_held("Meta");
+		if key.is_printable() && !is_shortcut {
+			let options = EventOptions {
+				char_code: key.key.chars().next().map_or(0, u32::from),
+				..self.options(key, repeat, true)
+			};
+			if !fire_event::key_press_with(&target, options) {
+				if let Some(editable) = editable {
+					editable.insert(&key.key, "insertText");
+				}
+			}
+			return;
+		}
+
+		match key.key.as_str() {
+			"Enter" => {
+				let options = EventOptions {
+					char_code: 13,
+					..self.options(key, repeat, true)
+				};
+				if !fire_event::key_press_with(&target, options) {
+					press_enter(&target, editable);
+				}
+			}
+			"Backspace" | "Delete" => {
+				if let Some(editable) = editable {
+					editable.delete(key.key == "Delete");
+				}
+			}
+			"Tab" => move_focus(!self.is_held("Shift")),
+			"ArrowLeft" | "ArrowRight" | "Home" | "End" => {
+				if let Some(editable) = editable {
+					let (start, end) = editable.selection();
+					let cursor = match key.key.as_str() {
+						"ArrowLeft" if start == end => start.saturating_sub(1),
+						"ArrowLeft" => start,
+						"ArrowRight" if start == end => (end + 1).min(editable.length()),
+						"ArrowRight" => end,
+						"Home" => 0,
+						_ => editable.length(),
+					};
+					editable.set_selection(cursor, cursor);
+				}
+			}
+			_ => {}
+		}
+	}
+
+	fn release(&mut self, key: &KeyDef) {
+		self.held.retain(|held| held != key);
+		let target = active_element();
+		if !fire_event::key_up_with(&target, self.options(key, false, false))
+			&& key.key == " "
+			&& is_clickable(&target)
+		{
+			fire_event::click(&target);
+		}
+	}
+}
+
+/// The default action of the `Enter` key: clicking buttons and links,
+/// adding a line break to text areas and submitting the form of an input.
+fn press_enter(target: &Element, editable: Option<Editable>) {
+	if (is_clickable(target) && !is_toggle(target)) || target.matches("a[href]").unwrap_or(false) {
+		fire_event::click(target);
+	} else if let Some(editable) = editable {
+		match editable {
+			Editable::Input(input) => submit_implicitly(&input),
+			editable => editable.insert("\n", "insertLineBreak"),
+		}
+	}
+}
+
+/// Submit the form of `input`, either by clicking its submit button or, if it
+/// has none, by submitting it directly when `input` is its only field.
+fn submit_implicitly(input: &web_sys::HtmlInputElement) {
+	let Some(form) = input.form() else {
+		return;
+	};
+
+	if let Ok(Some(submit)) = form.query_selector(
+		"button:not([type]), button[type='submit'], input[type='submit'], input[type='image']",
+	) {
+		if !is_disabled(&submit) {
+			fire_event::click(&submit);
+		}
+		return;
+	}
+
+	let fields = form.query_selector_all("input").unwrap();
+	let text_fields = (0..fields.length())
+		.filter_map(|i| fields.item(i))
+		.filter(|field| field.dyn_ref::<Element>().and_then(Editable::new).is_some())
+		.count();
+	if text_fields == 1 {
+		fire_event::submit(&form);
+	}
+}
+
+/// Returns `true` for elements activated by the space key.
+fn is_clickable(element: &Element) -> bool {
+	element.tag_name() == "BUTTON"
+		|| element
+			.dyn_ref::<web_sys::HtmlInputElement>()
+			.is_some_and(|input| {
+				matches!(
+					input.type_().as_str(),
+					"button" | "checkbox" | "image" | "radio" | "reset" | "submit"
+				)
+			})
+}
+
+/// Returns `true` for checkboxes and radio buttons, which `Enter` doesn't
+/// toggle.
+fn is_toggle(element: &Element) -> bool {
+	element
+		.dyn_ref::<web_sys::HtmlInputElement>()
+		.is_some_and(|input| matches!(input.type_().as_str(), "checkbox" | "radio"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn summary(input: &str) -> Vec<(String, String, u32, bool)> {
+		parse_keyboard(input)
+			.unwrap()
+			.into_iter()
+			.map(|action| {
+				(
+					action.key.key,
+					action.key.code,
+					action.presses,
+					action.release,
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn parses_characters_and_escapes() {
+		assert_eq!(
+			summary("a{{[[ "),
+			vec![
+				("a".into(), "KeyA".into(), 1, true),
+				("{".into(), "Unknown".into(), 1, true),
+				("[".into(), "BracketLeft".into(), 1, true),
+				(" ".into(), "Space".into(), 1, true),
+			]
+		);
+	}
+
+	#[test]
+	fn parses_descriptors() {
+		assert_eq!(
+			summary("{Shift>}A{/Shift}{Enter}[KeyB]{b>3/}"),
+			vec![
+				("Shift".into(), "ShiftLeft".into(), 1, false),
+				("A".into(), "KeyA".into(), 1, true),
+				("Shift".into(), "ShiftLeft".into(), 0, true),
+				("Enter".into(), "Enter".into(), 1, true),
+				("b".into(), "KeyB".into(), 1, true),
+				("b".into(), "KeyB".into(), 3, true),
+			]
+		);
+	}
+
+	#[test]
+	fn rejects_invalid_descriptors() {
+		assert!(parse_keyboard("{Enter").is_err());
+		assert!(parse_keyboard("{}").is_err());
+		assert!(parse_keyboard("{a>x}").is_err());
+		assert!(parse_keyboard("{/a>}").is_err());
+	}
+}
@@ -0,0 +1,409 @@
+//! Simulate how a user interacts with the page.
+//!
+//! Where [`fire_event`] dispatches a single event, these functions dispatch
+//! the whole sequence of events a browser would, i.e `click` fires
+//! `pointerdown`, `mousedown`, moves the focus, and then fires `pointerup`,
+//! `mouseup` and `click`. Disabled elements don't receive mouse events, and
+//! pointer interactions with elements that have `pointer-events: none` fail
+//! with a `PointerEventsDisabled` error. Files can be uploaded to file inputs
+//! with [`upload`], elements dragged with [`drag_and_drop`], and text moved
+//! through a stubbed clipboard with [`copy`], [`cut`] and [`paste`].
+//!
+//! ```rust
+//! # #[cfg(target_arch = "wasm32")]
+//! # mod hidden_example {
+//! use leptos_testing_library::dom::TestingLibraryError;
+//! use leptos_testing_library::prelude::*;
+//!
+//! fn sign_in(render: &impl DomQuery) -> Result<(), TestingLibraryError> {
+//! 	user_event::type_text(&render.get_by_label("Email")?, "user@example.com")?;
+//! 	user_event::type_text(&render.get_by_label("Password")?, "hunter2{Enter}")?;
+//! 	Ok(())
+//! }
+//! # }
+//! ```
+
+use std::cell::RefCell;
+
+pub use clipboard::*;
+pub use drag::*;
+use edit::*;
+use keyboard::*;
+use leptos::prelude::document;
+use leptos::prelude::window;
+pub use upload::*;
+
+use super::*;
+
+mod clipboard;
+mod drag;
+mod edit;
+mod keyboard;
+mod upload;
+
+thread_local! {
+	/// The element the pointer is currently over.
+	static POINTER_TARGET: RefCell<Option<Element>> = const { RefCell::new(None) };
+}
+
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button, input, select, textarea, iframe, \
+                                  summary, [tabindex], [contenteditable]";
+
+/// Click `element`: move the pointer over it, press and release the main
+/// button, and focus it (or its closest focusable ancestor).
+pub fn click(element: &Element) -> Result<(), TestingLibraryError> {
+	check_pointer_events(element)?;
+	move_pointer(element);
+	press_pointer(element, 1);
+	Ok(())
+}
+
+/// Double click `element`, which clicks it twice and then fires `dblclick`.
+pub fn dbl_click(element: &Element) -> Result<(), TestingLibraryError> {
+	check_pointer_events(element)?;
+	move_pointer(element);
+	press_pointer(element, 1);
+	press_pointer(element, 2);
+	if !is_disabled(element) {
+		fire_event::dbl_click_with(
+			element,
+			EventOptions {
+				detail: 2,
+				..Default::default()
+			},
+		);
+	}
+	Ok(())
+}
+
+/// Move the pointer over `element`, firing `pointerover`, `pointerenter`,
+/// `mouseover`, `mouseenter`, `pointermove` and `mousemove`.
+pub fn hover(element: &Element) -> Result<(), TestingLibraryError> {
+	check_pointer_events(element)?;
+	move_pointer(element);
+	Ok(())
+}
+
+/// Move the pointer away from `element`, firing `pointermove`, `mousemove`,
+/// `pointerout`, `pointerleave`, `mouseout` and `mouseleave`.
+pub fn unhover(element: &Element) -> Result<(), TestingLibraryError> {
+	check_pointer_events(element)?;
+	move_pointer(element);
+	leave_pointer(element);
+	POINTER_TARGET.with_borrow_mut(|target| *target = None);
+	Ok(())
+}
+
+/// Click `element` and type `text` into it, with the cursor at the end of its
+/// current value. `text` supports the same key descriptors as [`keyboard`],
+/// i.e `"hello{Enter}"`. Returns a `NotEditable` error if `element` isn't an
+/// enabled input, text area or content editable element.
+pub fn type_text(element: &Element, text: &str) -> Result<(), TestingLibraryError> {
+	let actions = parse_keyboard_input(text)?;
+	let editable = Editable::new(element).ok_or_else(|| {
+		TestingLibraryError::NotEditable {
+			html: element.outer_html(),
+		}
+	})?;
+	click(element)?;
+	editable.move_to_end();
+	Keyboard::default().run(actions);
+	Ok(())
+}
+
+/// Focus `element`, select its whole value and delete it. Returns a
+/// `NotEditable` error if `element` isn't an enabled input, text area or
+/// content editable element.
+pub fn clear(element: &Element) -> Result<(), TestingLibraryError> {
+	let editable = Editable::new(element).ok_or_else(|| {
+		TestingLibraryError::NotEditable {
+			html: element.outer_html(),
+		}
+	})?;
+	focus(element);
+	editable.select_all();
+	editable.delete(false);
+	Ok(())
+}
+
+/// Press keys on the focused element, or the body when nothing is focused.
+///
+/// Every character is pressed and released. `{Name}` presses a key by its
+/// `key`, i.e `{Enter}` or `{ArrowLeft}`, and `[Code]` by its `code`, i.e
+/// `[KeyA]`. `{Shift>}` holds a key down until `{/Shift}` releases it,
+/// `{a>3/}` presses a key three times, and `{{` and `[[` type a literal `{`
+/// and `[`. Keys still held at the end are not released.
+///
+/// Typing into inputs, text areas and content editable elements fires
+/// `keydown`, `keypress`, `beforeinput`, `input` and `keyup`, and cancelling
+/// an event skips what would have followed it.
+pub fn keyboard(text: &str) -> Result<(), TestingLibraryError> {
+	let actions = parse_keyboard_input(text)?;
+	Keyboard::default().run(actions);
+	Ok(())
+}
+
+/// Press `Tab` to move the focus to the next focusable element.
+pub fn tab() {
+	Keyboard::default().run(parse_keyboard("{Tab}").unwrap());
+}
+
+/// Press `Shift+Tab` to move the focus to the previous focusable element.
+pub fn shift_tab() {
+	Keyboard::default().run(parse_keyboard("{Shift>}{Tab}{/Shift}").unwrap());
+}
+
+/// Select the options of a `<select>` (or `role="listbox"`) element whose
+/// value or text is one of `values`. Disabled options are skipped, and on a
+/// single `<select>` the last value wins. Returns a `NotFound` error if a
+/// value doesn't match any option.
+pub fn select_options(element: &Element, values: &[&str]) -> Result<(), TestingLibraryError> {
+	set_options_selected(element, values, true)
+}
+
+/// Deselect the options of a multiple `<select>` (or `role="listbox"`)
+/// element whose value or text is one of `values`.
+pub fn deselect_options(element: &Element, values: &[&str]) -> Result<(), TestingLibraryError> {
+	set_options_selected(element, values, false)
+}
+
+fn parse_keyboard_input(text: &str) -> Result<Vec<KeyAction>, TestingLibraryError> {
+	parse_keyboard(text).map_err(|reason| {
+		TestingLibraryError::InvalidKeyboardInput {
+			input: text.to_string(),
+			reason,
+		}
+	})
+}
+
+fn set_options_selected(
+	element: &Element,
+	values: &[&str],
+	selected: bool,
+) -> Result<(), TestingLibraryError> {
+	check_pointer_events(element)?;
+	let method = if selected {
+		"select_options"
+	} else {
+		"deselect_options"
+	};
+
+	let Some(select) = element.dyn_ref::<web_sys::HtmlSelectElement>() else {
+		let options = get_all_matching(element, |item| {
+			element_role(item).as_deref() == Some("option")
+		});
+		for value in values {
+			let option = options
+				.iter()
+				.find(|option| option_matches(option, value))
+				.ok_or_else(|| {
+					TestingLibraryError::not_found(element, method, (*value).to_string())
+				})?;
+			let is_selected = option.get_attribute("aria-selected").as_deref() == Some("true");
+			if is_selected != selected {
+				click(option)?;
+			}
+		}
+		return Ok(());
+	};
+
+	let options = element.query_selector_all("option").unwrap();
+	let options = (0..options.length())
+		.filter_map(|i| options.item(i))
+		.filter_map(|option| option.dyn_into::<web_sys::HtmlOptionElement>().ok())
+		.collect::<Vec<_>>();
+	let mut matched = Vec::new();
+	for value in values {
+		let option = options
+			.iter()
+			.find(|option| option_matches(option, value))
+			.ok_or_else(|| TestingLibraryError::not_found(element, method, (*value).to_string()))?;
+		matched.push(option.clone());
+	}
+
+	if is_disabled(element) {
+		return Ok(());
+	}
+
+	move_pointer(element);
+	press_pointer(element, 1);
+	for option in matched {
+		if !option.disabled() && option.selected() != selected {
+			option.set_selected(selected);
+			fire_event::input(select);
+			fire_event::change(select);
+		}
+	}
+	Ok(())
+}
+
+fn option_matches(option: &Element, value: &str) -> bool {
+	option.get_attribute("value").as_deref() == Some(value)
+		|| default_normalizer(&option.text_content().unwrap_or_default()) == value
+}
+
+/// Returns a `PointerEventsDisabled` error if `element` has
+/// `pointer-events: none`.
+fn check_pointer_events(element: &Element) -> Result<(), TestingLibraryError> {
+	let pointer_events = window()
+		.get_computed_style(element)
+		.ok()
+		.flatten()
+		.and_then(|style| style.get_property_value("pointer-events").ok());
+	if pointer_events.as_deref() == Some("none") {
+		return Err(TestingLibraryError::PointerEventsDisabled {
+			html: element.outer_html(),
+		});
+	}
+	Ok(())
+}
+
+/// Returns `true` if `element` is a disabled form control, including
+/// controls in a disabled `<fieldset>`.
+pub(crate) fn is_disabled(element: &Element) -> bool {
+	element.matches(":disabled").unwrap_or(false)
+}
+
+pub(crate) fn active_element() -> Element {
+	let document = document();
+	document
+		.active_element()
+		.or_else(|| document.body().map(Into::into))
+		.expect("the document to have a body")
+}
+
+fn pointer_options(buttons: u16, detail: i32) -> EventOptions {
+	EventOptions {
+		buttons,
+		detail,
+		pointer_id: 1,
+		pointer_type: "mouse".into(),
+		is_primary: true,
+		..Default::default()
+	}
+}
+
+/// Move the pointer to `element`, leaving the element it was over before.
+fn move_pointer(element: &Element) {
+	let previous = POINTER_TARGET.with_borrow_mut(|target| target.replace(element.clone()));
+	if previous.as_ref() != Some(element) {
+		if let Some(previous) = previous.filter(|previous| previous.is_connected()) {
+			leave_pointer(&previous);
+		}
+		fire_event::pointer_over_with(element, pointer_options(0, 0));
+		fire_event::pointer_enter_with(element, pointer_options(0, 0));
+		if !is_disabled(element) {
+			fire_event::mouse_over(element);
+			fire_event::mouse_enter(element);
+		}
+	}
+
+	fire_event::pointer_move_with(element, pointer_options(0, 0));
+	if !is_disabled(element) {
+		fire_event::mouse_move(element);
+	}
+}
+
+fn leave_pointer(element: &Element) {
+	fire_event::pointer_out_with(element, pointer_options(0, 0));
+	fire_event::pointer_leave_with(element, pointer_options(0, 0));
+	if !is_disabled(element) {
+		fire_event::mouse_out(element);
+		fire_event::mouse_leave(element);
+	}
+}
+
+/// Press and release the main button on `element`.
+fn press_pointer(element: &Element, click_count: i32) {
+	let disabled = is_disabled(element);
+	fire_event::pointer_down_with(element, pointer_options(1, click_count));
+	if !disabled {
+		let cancelled = fire_event::mouse_down_with(
+			element,
+			EventOptions {
+				buttons: 1,
+				detail: click_count,
+				..Default::default()
+			},
+		);
+		if !cancelled {
+			focus_closest(element);
+		}
+	}
+
+	fire_event::pointer_up_with(element, pointer_options(0, click_count));
+	if !disabled {
+		fire_event::mouse_up_with(
+			element,
+			EventOptions {
+				detail: click_count,
+				..Default::default()
+			},
+		);
+		fire_event::click_with(
+			element,
+			EventOptions {
+				detail: click_count,
+				..Default::default()
+			},
+		);
+	}
+}
+
+/// Focus `element` or its closest focusable ancestor, or blur the focused
+/// element when there is none.
+fn focus_closest(element: &Element) {
+	match element.closest(FOCUSABLE_SELECTOR).ok().flatten() {
+		Some(focusable) if !is_disabled(&focusable) => focus(&focusable),
+		_ => {
+			if let Some(active) = document().active_element() {
+				if let Some(active) = active.dyn_ref::<HtmlElement>() {
+					_ = active.blur();
+				}
+			}
+		}
+	}
+}
+
+fn focus(element: &Element) {
+	if let Some(element) = element.dyn_ref::<HtmlElement>() {
+		_ = element.focus();
+	}
+}
+
+/// Move the focus to the next (or previous) element in the tab order.
+pub(crate) fn move_focus(forward: bool) {
+	let elements = document().query_selector_all(FOCUSABLE_SELECTOR).unwrap();
+	let mut tabbable = (0..elements.length())
+		.filter_map(|i| elements.item(i))
+		.filter_map(|element| element.dyn_into::<HtmlElement>().ok())
+		.filter(|element| {
+			element.tab_index() >= 0 && !is_disabled(element) && !is_inaccessible(element)
+		})
+		.collect::<Vec<_>>();
+	// Positive tab indexes come first, in order, then everything else in
+	// document order.
+	tabbable.sort_by_key(|element| {
+		match element.tab_index() {
+			0 => i32::MAX,
+			index => index,
+		}
+	});
+
+	if tabbable.is_empty() {
+		return;
+	}
+
+	let active = document().active_element();
+	let position = tabbable
+		.iter()
+		.position(|element| Some(element.unchecked_ref::<Element>()) == active.as_ref());
+	let length = tabbable.len();
+	let next = match (position, forward) {
+		(Some(index), true) => (index + 1) % length,
+		(Some(index), false) => (index + length - 1) % length,
+		(None, true) => 0,
+		(None, false) => length - 1,
+	};
+	_ = tabbable[next].focus();
+}
@@ -0,0 +1,133 @@
+use super::*;
+
+/// An in-memory file to [`upload`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadFile {
+	name: String,
+	contents: Vec<u8>,
+	mime_type: String,
+}
+
+impl UploadFile {
+	/// Create a file called `name` holding `contents`, with a MIME type like
+	/// `"image/png"`.
+	pub fn new<N, C, M>(name: N, contents: C, mime_type: M) -> Self
+	where
+		N: Into<String>,
+		C: Into<Vec<u8>>,
+		M: Into<String>,
+	{
+		Self {
+			name: name.into(),
+			contents: contents.into(),
+			mime_type: mime_type.into(),
+		}
+	}
+
+	/// Returns `true` if the `accept` attribute of a file input allows this
+	/// file, either by extension (`.png`), by MIME type (`image/png`) or by a
+	/// wildcard MIME type (`image/*`).
+	fn is_accepted(&self, accept: &str) -> bool {
+		let name = self.name.to_lowercase();
+		let mime_type = self.mime_type.to_lowercase();
+		let mut tokens = accept
+			.split(',')
+			.map(|token| token.trim().to_lowercase())
+			.filter(|token| !token.is_empty())
+			.peekable();
+
+		tokens.peek().is_none()
+			|| tokens.any(|token| {
+				if token.starts_with('.') {
+					name.ends_with(&token)
+				} else if let Some(prefix) = token.strip_suffix('*') {
+					mime_type.starts_with(prefix)
+				} else {
+					mime_type == token
+				}
+			})
+	}
+
+	fn to_file(&self) -> web_sys::File {
+		let bits = js_sys::Array::of1(&js_sys::Uint8Array::from(self.contents.as_slice()));
+		let options = web_sys::FilePropertyBag::new();
+		options.set_type(&self.mime_type);
+		web_sys::File::new_with_u8_array_sequence_and_options(&bits, &self.name, &options)
+			.expect("the file to be created")
+	}
+}
+
+/// Upload `files` to a file input, or to the file input of a `<label>`. This
+/// clicks the element, then sets the input's `files` and fires `input` and
+/// `change`. Files that the input's `accept` attribute doesn't allow are left
+/// out, and only the first file is kept unless the input is `multiple`.
+///
+/// Returns a `NotFileInput` error if the element isn't a file input or a
+/// label of one. Nothing happens when the input is disabled.
+///
+/// ```rust
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos_testing_library::dom::user_event::UploadFile;
+/// use leptos_testing_library::prelude::*;
+///
+/// fn upload_avatar(render: &impl DomQuery) {
+/// 	let avatar = UploadFile::new("avatar.png", vec![137, 80, 78, 71], "image/png");
+/// 	user_event::upload(&render.get_by_label("Avatar").unwrap(), &[avatar]).unwrap();
+/// }
+/// # }
+/// ```
+pub fn upload(element: &Element, files: &[UploadFile]) -> Result<(), TestingLibraryError> {
+	let input = element
+		.dyn_ref::<web_sys::HtmlLabelElement>()
+		.and_then(web_sys::HtmlLabelElement::control)
+		.map_or_else(|| element.clone(), Element::from)
+		.dyn_into::<web_sys::HtmlInputElement>()
+		.ok()
+		.filter(|input| input.type_() == "file")
+		.ok_or_else(|| {
+			TestingLibraryError::NotFileInput {
+				html: element.outer_html(),
+			}
+		})?;
+
+	if is_disabled(&input) {
+		return Ok(());
+	}
+
+	click(element)?;
+
+	let accept = input.accept();
+	let mut files = files
+		.iter()
+		.filter(|file| file.is_accepted(&accept))
+		.collect::<Vec<_>>();
+	if !input.multiple() {
+		files.truncate(1);
+	}
+
+	let data_transfer = web_sys::DataTransfer::new().unwrap();
+	for file in files {
+		_ = data_transfer.items().add_with_file(&file.to_file());
+	}
+	input.set_files(data_transfer.files().as_ref());
+	fire_event::input(&input);
+	fire_event::change(&input);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn accepts_files() {
+		let file = UploadFile::new("Avatar.PNG", vec![], "image/png");
+		assert!(file.is_accepted(""));
+		assert!(file.is_accepted(".png"));
+		assert!(file.is_accepted("image/*"));
+		assert!(file.is_accepted(".jpg, image/png"));
+		assert!(!file.is_accepted(".csv,text/csv"));
+		assert!(!file.is_accepted("video/*"));
+	}
+}
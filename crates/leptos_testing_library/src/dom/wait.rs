@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use leptos::prelude::document;
+use leptos::prelude::window;
+use leptos::task::Executor;
+use leptos::task::tick;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::MutationObserver;
+use web_sys::MutationObserverInit;
+
+use super::*;
+
+/// Retry a `get_by_X` style query using the global async configuration,
+/// wrapping the last error in a `Timeout` error when it never succeeds.
+pub(crate) async fn find_inner<T, F>(callback: F) -> Result<T, TestingLibraryError>
+where
+	F: FnMut() -> Result<T, TestingLibraryError>,
+{
+	let config = get_config();
+	poll_until(
+		config.async_util_timeout,
+		config.async_util_interval,
+		callback,
+	)
+	.await
+	.map_err(|error| TestingLibraryError::timeout(config.async_util_timeout, "an element", error))
+}
+
+/// Retry a `get_all_by_X` style query until it returns at least one element.
+/// While it doesn't, the error of the matching `get_by_X` query is kept, so a
+/// timeout explains what was rendered instead.
+pub(crate) async fn find_all_inner<F, G>(
+	mut get_all: F,
+	mut get_one: G,
+) -> Result<Vec<TestElement>, TestingLibraryError>
+where
+	F: FnMut() -> Vec<TestElement>,
+	G: FnMut() -> Result<TestElement, TestingLibraryError>,
+{
+	find_inner(|| {
+		let list = get_all();
+		if list.is_empty() {
+			get_one().map(|element| vec![element])
+		} else {
+			Ok(list)
+		}
+	})
+	.await
+}
+
+/// Keep calling `callback` until it succeeds or `timeout` has elapsed. Between
+/// attempts this waits for the next DOM mutation, or at most `interval`, and
+/// then lets the Leptos executor run so pending effects and resources settle.
+///
+/// The attempts made before the timeout build brief query errors, see
+/// [`with_brief_errors`]. Once the timeout elapses `callback` is called one
+/// last time, and its error, with the DOM and suggestions, is returned.
+pub(crate) async fn poll_until<T, E, F>(
+	timeout: Duration,
+	interval: Duration,
+	mut callback: F,
+) -> Result<T, E>
+where
+	F: FnMut() -> Result<T, E>,
+{
+	// `tick` needs an executor, which isn't set up until something is mounted.
+	_ = Executor::init_wasm_bindgen();
+	let timeout = timeout.as_secs_f64() * 1000.0;
+	let started = js_sys::Date::now();
+
+	loop {
+		tick().await;
+
+		let remaining = timeout - (js_sys::Date::now() - started);
+		if remaining <= 0.0 {
+			return callback();
+		}
+		if let Ok(value) = with_brief_errors(&mut callback) {
+			return Ok(value);
+		}
+
+		next_dom_mutation(remaining.min(interval.as_secs_f64() * 1000.0)).await;
+	}
+}
+
+/// Resolves on the next mutation anywhere in the document, or after `max_wait`
+/// milliseconds, whichever happens first.
+async fn next_dom_mutation(max_wait: f64) {
+	let window = window();
+	let mut observer = None;
+	let mut handle = None;
+	let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+		handle = window
+			.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, max_wait.ceil() as i32)
+			.ok();
+
+		if let (Ok(mutation_observer), Some(root)) = (
+			MutationObserver::new(&resolve),
+			document().document_element(),
+		) {
+			let options = MutationObserverInit::new();
+			options.set_subtree(true);
+			options.set_child_list(true);
+			options.set_attributes(true);
+			options.set_character_data(true);
+			_ = mutation_observer.observe_with_options(&root, &options);
+			observer = Some(mutation_observer);
+		}
+	});
+
+	_ = JsFuture::from(promise).await;
+
+	if let Some(observer) = observer {
+		observer.disconnect();
+	}
+
+	if let Some(handle) = handle {
+		window.clear_timeout_with_handle(handle);
+	}
+}
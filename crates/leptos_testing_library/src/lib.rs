@@ -1,7 +1,20 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use dom::TestingLibraryError;
 use dom::prelude::*;
 use leptos::IntoView;
 use leptos::prelude::*;
+use leptos::tachys::view::any_view::AnyViewState;
+use leptos::task::tick;
+use leptos_meta::provide_meta_context;
+use leptos_router::components::Router;
+use send_wrapper::SendWrapper;
 use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
 use web_sys::HtmlElement;
 
 /// Renders a Leptos component for testing purposes.
@@ -84,32 +97,531 @@ where
 	F: FnOnce() -> N + 'static,
 	N: IntoView,
 {
-	let body = document().body().unwrap();
-	let test_wrapper = document().create_element("div").unwrap();
-	body.append_child(&test_wrapper).unwrap();
-	let _unmount = mount_to(test_wrapper.clone().unchecked_into::<HtmlElement>(), f);
+	render_for_test_with(f, RenderOptions::default())
+}
+
+/// Options for [`render_for_test_with`].
+#[derive(Clone, Default)]
+#[must_use]
+pub struct RenderOptions {
+	/// Wraps the rendered component, usually to provide the context it needs.
+	/// Defaults to no wrapper. Use [`RenderOptions::with_wrapper`] to add
+	/// one.
+	pub wrapper: Option<Wrapper>,
+	/// The element the component is rendered into. Defaults to a new `div`
+	/// appended to the base element. A container passed here is emptied but
+	/// not removed from the document when the render is dropped.
+	pub container: Option<HtmlElement>,
+	/// The element the test container is appended to. Defaults to the
+	/// container passed in [`RenderOptions::container`], or to
+	/// `document.body`. Query it with
+	/// [`LeptosTestingLibraryRender::base_element`] to find content rendered
+	/// outside of the container, i.e by a `<Portal mount=.../>`.
+	pub base_element: Option<HtmlElement>,
+}
+
+/// A component that wraps the rendered component. It is given the rendered
+/// component as its children. See [`RenderOptions::wrapper`].
+pub type Wrapper = Arc<dyn Fn(Children) -> AnyView + Send + Sync>;
+
+impl RenderOptions {
+	/// Wrap the rendered component with `wrapper`, inside of the wrappers
+	/// that were already added. Create the options once in a helper function
+	/// to share the same providers across a test module.
+	///
+	/// ```
+	/// # #[cfg(target_arch = "wasm32")]
+	/// # mod hidden_example {
+	/// use leptos::prelude::*;
+	/// use leptos_testing_library::prelude::*;
+	///
+	/// #[component]
+	/// fn ThemeProvider(children: Children) -> impl IntoView {
+	/// 	view! { <div class="theme-dark">{children()}</div> }
+	/// }
+	///
+	/// fn options() -> RenderOptions {
+	/// 	RenderOptions::default()
+	/// 		.with_router("/users/42")
+	/// 		.with_meta()
+	/// 		.with_wrapper(|children| {
+	/// 			view! { <ThemeProvider>{children()}</ThemeProvider> }.into_any()
+	/// 		})
+	/// }
+	/// # }
+	/// ```
+	pub fn with_wrapper<F>(mut self, wrapper: F) -> Self
+	where
+		F: Fn(Children) -> AnyView + Send + Sync + 'static,
+	{
+		self.wrapper = Some(match self.wrapper {
+			Some(outer) => {
+				let inner = Arc::new(wrapper);
+				Arc::new(move |children: Children| {
+					let inner = inner.clone();
+					outer(Box::new(move || inner(children)))
+				})
+			}
+			None => Arc::new(wrapper),
+		});
+		self
+	}
+
+	/// Provide `value` as context to the rendered component, with
+	/// `provide_context`.
+	pub fn with_context<T>(self, value: T) -> Self
+	where
+		T: Clone + Send + Sync + 'static,
+	{
+		self.with_wrapper(move |children| {
+			provide_context(value.clone());
+			children()
+		})
+	}
+
+	/// Replace the URL of the document with `url` before the component is
+	/// rendered, without reloading the page. A `leptos_router` `<Router>`
+	/// then starts at `url`, see [`RenderOptions::with_router`].
+	///
+	/// The URL the document had before is restored when the component is
+	/// unmounted, so navigating during a test doesn't leak into the next one.
+	pub fn with_url(self, url: impl Into<String>) -> Self {
+		let url = url.into();
+		self.with_wrapper(move |children| {
+			let original = window().location().href().unwrap();
+			replace_url(&url);
+			Owner::on_cleanup(move || replace_url(&original));
+			children()
+		})
+	}
+
+	/// Render the component inside of a `leptos_router` `<Router>` that starts
+	/// at `initial_url`, so it can use `<Routes>`, `<A>` and the router hooks.
+	/// The URL is set with [`RenderOptions::with_url`], and restored when the
+	/// component is unmounted.
+	pub fn with_router(self, initial_url: impl Into<String>) -> Self {
+		self.with_url(initial_url)
+			.with_wrapper(|children| view! { <Router>{children()}</Router> }.into_any())
+	}
+
+	/// Provide the `leptos_meta` context with `provide_meta_context`, so the
+	/// component can render `<Title>`, `<Meta>` and the other meta
+	/// components.
+	pub fn with_meta(self) -> Self {
+		self.with_wrapper(|children| {
+			provide_meta_context();
+			children()
+		})
+	}
+}
+
+/// Replace the URL of the document without adding a history entry.
+fn replace_url(url: &str) {
+	window()
+		.history()
+		.unwrap()
+		.replace_state_with_url(&JsValue::NULL, "", Some(url))
+		.unwrap();
+}
+
+impl fmt::Debug for RenderOptions {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("RenderOptions")
+			.field("wrapper", &self.wrapper.is_some())
+			.field("container", &self.container)
+			.field("base_element", &self.base_element)
+			.finish()
+	}
+}
+
+/// The same as [`render_for_test`] but with custom [`RenderOptions`].
+///
+/// ```
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::portal::Portal;
+/// use leptos::prelude::*;
+/// use leptos_testing_library::prelude::*;
+/// use wasm_bindgen_test::*;
+///
+/// #[wasm_bindgen_test]
+/// async fn test_modal() {
+/// 	let base_element = document().create_element("main").unwrap();
+/// 	document()
+/// 		.body()
+/// 		.unwrap()
+/// 		.append_child(&base_element)
+/// 		.unwrap();
+/// 	let mount = base_element.clone();
+/// 	let render = render_for_test_with(
+/// 		move || view! { <Portal mount=mount><p>"Modal"</p></Portal> },
+/// 		RenderOptions {
+/// 			base_element: Some(base_element.unchecked_into()),
+/// 			..Default::default()
+/// 		},
+/// 	);
+///
+/// 	assert!(render.base_element().find_by_text("Modal").await.is_ok());
+/// }
+/// # }
+/// ```
+pub fn render_for_test_with<F, N>(f: F, options: RenderOptions) -> LeptosTestingLibraryRender<N>
+where
+	F: FnOnce() -> N + 'static,
+	N: IntoView,
+{
+	mount((), move |_| f(), options)
+}
+
+/// The same as [`render_for_test`] but the component is given `props` as a
+/// signal. Call [`LeptosTestingLibraryRender::rerender`] to pass it new
+/// props, like a parent component would, and check how it reacts.
+///
+/// ```
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::prelude::*;
+/// use leptos_testing_library::prelude::*;
+/// use wasm_bindgen_test::*;
+///
+/// #[component]
+/// fn Greeting(#[prop(into)] name: Signal<String>) -> impl IntoView {
+/// 	view! { <p>"Hello " {name}</p> }
+/// }
+///
+/// #[wasm_bindgen_test]
+/// async fn test_greeting() {
+/// 	let render = render_for_test_with_props("Alice".to_string(), |name| {
+/// 		view! { <Greeting name=name /> }
+/// 	});
+/// 	assert!(render.get_by_text("Hello Alice").is_ok());
+///
+/// 	render.rerender("Bob".to_string()).await;
+/// 	assert!(render.get_by_text("Hello Bob").is_ok());
+/// }
+/// # }
+/// ```
+pub fn render_for_test_with_props<P, F, N>(props: P, f: F) -> LeptosTestingLibraryRender<N, P>
+where
+	P: Send + Sync + 'static,
+	F: FnOnce(ReadSignal<P>) -> N + 'static,
+	N: IntoView,
+{
+	mount(props, f, RenderOptions::default())
+}
+
+/// The same as [`render_for_test_with_props`] but rendered with `options`,
+/// i.e to wrap the component in the providers it needs. See
+/// [`RenderOptions`].
+pub fn render_for_test_with_props_and_options<P, F, N>(
+	props: P,
+	f: F,
+	options: RenderOptions,
+) -> LeptosTestingLibraryRender<N, P>
+where
+	P: Send + Sync + 'static,
+	F: FnOnce(ReadSignal<P>) -> N + 'static,
+	N: IntoView,
+{
+	mount(props, f, options)
+}
+
+fn mount<P, F, N>(props: P, f: F, options: RenderOptions) -> LeptosTestingLibraryRender<N, P>
+where
+	P: Send + Sync + 'static,
+	F: FnOnce(ReadSignal<P>) -> N + 'static,
+	N: IntoView,
+{
+	let remove_container = options.container.is_none();
+	let (container, base_element) = match (options.container, options.base_element) {
+		(Some(container), base_element) => {
+			let base_element = base_element.unwrap_or_else(|| container.clone());
+			(container, base_element)
+		}
+		(None, base_element) => {
+			let base_element = base_element.unwrap_or_else(|| document().body().unwrap());
+			let container = document().create_element("div").unwrap();
+			base_element.append_child(&container).unwrap();
+			(container.unchecked_into(), base_element)
+		}
+	};
+	let test_wrapper: web_sys::Element = container.clone().into();
+	let props = ArcRwSignal::new(props);
+	let read_props = props.read_only();
+	// The wrapper must run first so the context it provides is available to
+	// the component, but components aren't `Send`.
+	let f = SendWrapper::new(f);
+	let wrapper = options.wrapper;
+	let unmount = mount_to(container, move || {
+		let children: Children = Box::new(move || f.take()(read_props.into()).into_any());
+		match wrapper {
+			Some(wrapper) => wrapper(children),
+			None => children(),
+		}
+	});
+	MOUNTED.with_borrow_mut(|mounted| {
+		mounted.push(Mounted {
+			container: test_wrapper.clone(),
+			remove_container,
+			unmount: Some(unmount),
+		});
+	});
 
 	LeptosTestingLibraryRender {
-		_unmount,
 		element: test_wrapper,
+		base_element: base_element.into(),
+		props,
+		view: PhantomData,
 	}
 }
 
-pub struct LeptosTestingLibraryRender<N: IntoView> {
-	_unmount: UnmountHandle<N::State>,
+thread_local! {
+	/// Every render that hasn't been dropped or cleaned up yet.
+	static MOUNTED: RefCell<Vec<Mounted>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A rendered container and the handle that keeps its component mounted.
+struct Mounted {
+	container: web_sys::Element,
+	/// Whether the container was created by the render, rather than passed
+	/// in [`RenderOptions::container`].
+	remove_container: bool,
+	unmount: Option<UnmountHandle<AnyViewState>>,
+}
+
+impl Mounted {
+	/// Unmount the component and remove the container from the document,
+	/// if it was created by the render.
+	fn tear_down(self) {
+		drop(self.unmount);
+		if self.remove_container {
+			self.container.remove();
+		}
+	}
+}
+
+/// Unmount every component rendered by [`render_for_test`] that is still
+/// mounted, and remove their containers from the document.
+///
+/// Renders are cleaned up when they are dropped, so this is only needed when
+/// a render outlives the test that created it, i.e when it is leaked or kept
+/// in a static. Call it at the start of a test that must not see DOM left
+/// behind by earlier tests.
+///
+/// ```
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::prelude::*;
+/// use leptos_testing_library::prelude::*;
+/// use wasm_bindgen_test::*;
+///
+/// #[wasm_bindgen_test]
+/// fn test_empty_screen() {
+/// 	cleanup();
+/// 	assert!(screen().query_by_role("dialog").unwrap().is_none());
+/// }
+/// # }
+/// ```
+pub fn cleanup() {
+	let mounted = MOUNTED.with_borrow_mut(std::mem::take);
+	for mounted in mounted {
+		mounted.tear_down();
+	}
+}
+
+/// A component rendered by [`render_for_test`]. Use the [`DomQuery`] methods
+/// to query the container it was rendered into.
+///
+/// The component is unmounted and its container removed from the document
+/// when the render is dropped, so DOM doesn't leak from one test to the
+/// next. Renders created by [`render_for_test_with_props`] also hold the
+/// props of the component, of type `P`.
+pub struct LeptosTestingLibraryRender<N: IntoView, P = ()> {
 	element: web_sys::Element,
+	base_element: web_sys::Element,
+	props: ArcRwSignal<P>,
+	view: PhantomData<N>,
 }
 
-impl<N: IntoView> HoldsElement for LeptosTestingLibraryRender<N> {
+impl<N: IntoView, P> LeptosTestingLibraryRender<N, P> {
+	/// Query the base element the container was appended to, rather than
+	/// just the container. See [`RenderOptions::base_element`].
+	pub fn base_element(&self) -> ElementWrapper {
+		ElementWrapper(&self.base_element)
+	}
+
+	/// Unmount the component, running its cleanup. The empty container stays
+	/// in the document until the render is dropped, so it can still be
+	/// queried to check that nothing was left behind.
+	pub fn unmount(&self) {
+		let unmount = MOUNTED.with_borrow_mut(|mounted| {
+			mounted
+				.iter_mut()
+				.find(|mounted| mounted.container == self.element)
+				.and_then(|mounted| mounted.unmount.take())
+		});
+		drop(unmount);
+	}
+}
+
+impl<N: IntoView, P: 'static> LeptosTestingLibraryRender<N, P> {
+	/// Pass new props to the component, in the same container, and wait for
+	/// the DOM to update. The component isn't mounted again: only what
+	/// depends on the props is updated, and the effects that read them are
+	/// cleaned up and re-run.
+	pub async fn rerender(&self, props: P) {
+		self.props.set(props);
+		tick().await;
+	}
+}
+
+impl<N: IntoView, P> Drop for LeptosTestingLibraryRender<N, P> {
+	fn drop(&mut self) {
+		let mounted = MOUNTED.with_borrow_mut(|mounted| {
+			mounted
+				.iter()
+				.position(|mounted| mounted.container == self.element)
+				.map(|index| mounted.remove(index))
+		});
+
+		// Renders that were already cleaned up aren't mounted anymore.
+		if let Some(mounted) = mounted {
+			mounted.tear_down();
+		}
+	}
+}
+
+impl<N: IntoView, P> HoldsElement for LeptosTestingLibraryRender<N, P> {
 	fn element(&self) -> ElementWrapper {
 		ElementWrapper(&self.element)
 	}
 }
 
+/// Options for [`wait_for_with_options`]. The defaults are taken from the
+/// global [`Config`].
+#[derive(Clone, Debug)]
+pub struct WaitForOptions {
+	/// How long to keep retrying before giving up.
+	pub timeout: Duration,
+	/// The longest to wait between two attempts when the DOM doesn't change.
+	pub interval: Duration,
+}
+
+impl Default for WaitForOptions {
+	fn default() -> Self {
+		let config = get_config();
+		Self {
+			timeout: config.async_util_timeout,
+			interval: config.async_util_interval,
+		}
+	}
+}
+
+/// Waits until `callback` returns `Ok`. The callback is re-run after every
+/// DOM mutation and executor tick until it passes or the configured timeout
+/// elapses, in which case the last error it returned is returned.
+///
+/// ```
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::prelude::*;
+/// use leptos_testing_library::prelude::*;
+/// use wasm_bindgen_test::*;
+///
+/// #[wasm_bindgen_test]
+/// async fn test_resource() {
+/// 	let render = render_for_test(|| {
+/// 		let count = LocalResource::new(|| async { 42 });
+/// 		view! {
+/// 			<Suspense fallback=|| "Loading...">
+/// 				<span id="count">{move || Suspend::new(async move { count.await })}</span>
+/// 			</Suspense>
+/// 		}
+/// 	});
+///
+/// 	wait_for(|| {
+/// 		match render.get_by_id("count") {
+/// 			Ok(element) if element.display_text() == "42" => Ok(()),
+/// 			_ => Err("The count hasn't loaded yet"),
+/// 		}
+/// 	})
+/// 	.await
+/// 	.unwrap();
+/// }
+/// # }
+/// ```
+pub async fn wait_for<T, E, F>(callback: F) -> Result<T, E>
+where
+	F: FnMut() -> Result<T, E>,
+{
+	wait_for_with_options(callback, WaitForOptions::default()).await
+}
+
+/// The same as [`wait_for`] but with a custom timeout and interval.
+pub async fn wait_for_with_options<T, E, F>(callback: F, options: WaitForOptions) -> Result<T, E>
+where
+	F: FnMut() -> Result<T, E>,
+{
+	dom::poll_until(options.timeout, options.interval, callback).await
+}
+
+/// Waits until `element` has been removed from the document. Returns an
+/// `AlreadyRemoved` error if the element isn't in the document to begin with,
+/// and a `Timeout` error if it is still there once the configured timeout
+/// elapses.
+pub async fn wait_for_element_to_be_removed(
+	element: &TestElement,
+) -> Result<(), TestingLibraryError> {
+	if !element.is_connected() {
+		return Err(TestingLibraryError::AlreadyRemoved);
+	}
+
+	let options = WaitForOptions::default();
+	wait_for_with_options(
+		|| {
+			if element.is_connected() {
+				Err(TestingLibraryError::NotRemoved {
+					html: element.as_html_string(),
+				})
+			} else {
+				Ok(())
+			}
+		},
+		options.clone(),
+	)
+	.await
+	.map_err(|error| {
+		TestingLibraryError::timeout(options.timeout, "the element to be removed", error)
+	})
+}
+
 pub mod dom;
+mod router;
+
+pub use router::RouterRender;
+pub use router::render_with_router;
+
+/// Used by the macros of this crate. Not public API.
+#[doc(hidden)]
+pub mod __private {
+	pub use insta;
+}
 
 pub mod prelude {
 	pub use super::LeptosTestingLibraryRender;
+	pub use super::RenderOptions;
+	pub use super::RouterRender;
+	pub use super::WaitForOptions;
+	pub use super::Wrapper;
+	pub use super::cleanup;
 	pub use super::dom::prelude::*;
 	pub use super::render_for_test;
+	pub use super::render_for_test_with;
+	pub use super::render_for_test_with_props;
+	pub use super::render_for_test_with_props_and_options;
+	pub use super::render_with_router;
+	pub use super::wait_for;
+	pub use super::wait_for_element_to_be_removed;
+	pub use super::wait_for_with_options;
+	pub use crate::assert_dom_snapshot;
 }
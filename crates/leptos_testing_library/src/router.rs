@@ -0,0 +1,350 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use derive_more::Deref;
+use js_sys::Reflect;
+use leptos::IntoView;
+use leptos::prelude::*;
+use leptos::task::tick;
+use leptos_router::NavigateOptions;
+use leptos_router::components::Router;
+use leptos_router::hooks::use_navigate;
+use leptos_router::location::BrowserUrl;
+use leptos_router::location::LocationProvider;
+use leptos_router::location::Url;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::closure::Closure;
+use web_sys::Element;
+use web_sys::MouseEvent;
+
+use crate::LeptosTestingLibraryRender;
+use crate::RenderOptions;
+use crate::dom::ElementWrapper;
+use crate::dom::HoldsElement;
+use crate::render_for_test_with;
+
+/// Render `routes` inside of a `leptos_router` `<Router>` that starts at
+/// `url` and navigates in memory. The returned [`RouterRender`] can be
+/// queried like any other render, navigated with [`RouterRender::navigate`]
+/// and [`RouterRender::go_back`], and tells where the router is with
+/// [`RouterRender::current_location`] and [`RouterRender::history`].
+///
+/// Clicking a link in the render, [`RouterRender::navigate`] and
+/// `use_navigate` all add to the in-memory history of the render, so the URL
+/// and the browser history of the page running the tests are left untouched.
+///
+/// ```
+/// # #[cfg(target_arch = "wasm32")]
+/// # mod hidden_example {
+/// use leptos::prelude::*;
+/// use leptos_router::components::A;
+/// use leptos_router::components::Route;
+/// use leptos_router::components::Routes;
+/// use leptos_router::hooks::use_params_map;
+/// use leptos_router::path;
+/// use leptos_testing_library::prelude::*;
+/// use wasm_bindgen_test::*;
+///
+/// #[component]
+/// fn User() -> impl IntoView {
+/// 	let params = use_params_map();
+/// 	view! { <p>"User " {move || params.read().get("id")}</p> }
+/// }
+///
+/// #[wasm_bindgen_test]
+/// async fn test_users() {
+/// 	let render = render_with_router(
+/// 		|| {
+/// 			view! {
+/// 				<A href="/users/7">"Next"</A>
+/// 				<Routes fallback=|| "Not found">
+/// 					<Route path=path!("/users/:id") view=User />
+/// 				</Routes>
+/// 			}
+/// 		},
+/// 		"/users/42",
+/// 	);
+/// 	assert!(render.find_by_text("User 42").await.is_ok());
+///
+/// 	render.get_by_text("Next").unwrap().click();
+/// 	assert!(render.find_by_text("User 7").await.is_ok());
+/// 	assert_eq!(render.history(), ["/users/42", "/users/7"]);
+///
+/// 	render.go_back().await;
+/// 	assert_eq!(render.current_location(), "/users/42");
+/// }
+/// # }
+/// ```
+pub fn render_with_router<F, N>(routes: F, url: impl Into<String>) -> RouterRender<N>
+where
+	F: FnOnce() -> N + 'static,
+	N: IntoView,
+{
+	let url = url.into();
+	let memory = Rc::new(RefCell::new(None));
+	let render = render_for_test_with(
+		{
+			let memory = memory.clone();
+			move || {
+				// `<Router>` starts at the URL of the document and updates it when
+				// navigating, so its location is pointed at a history kept in
+				// memory instead.
+				let location = use_context::<BrowserUrl>().expect("the routes to be in a <Router>");
+				let history = MemoryHistory::new(location.as_url().clone(), &url);
+				history.intercept_browser_history();
+				*memory.borrow_mut() = Some((history, Rc::new(use_navigate()) as Navigate));
+				routes()
+			}
+		},
+		RenderOptions::default()
+			.with_wrapper(|children| view! { <Router>{children()}</Router> }.into_any()),
+	);
+	let (history, navigate) = memory
+		.take()
+		.expect("the routes to be rendered inside of the router");
+	let link_clicks = handle_link_clicks(&render.element().0.clone(), &history, navigate.clone());
+
+	RouterRender {
+		render,
+		history,
+		navigate,
+		link_clicks,
+	}
+}
+
+type Navigate = Rc<dyn Fn(&str, NavigateOptions)>;
+
+/// The history of a [`RouterRender`]: every location it has been at, from the
+/// oldest to the current one.
+#[derive(Clone)]
+struct MemoryHistory {
+	/// The location of the `<Router>`.
+	url: ArcRwSignal<Url>,
+	entries: Rc<RefCell<Vec<String>>>,
+}
+
+impl MemoryHistory {
+	/// Move the router at `url` to `initial_url`, as the first entry.
+	fn new(url: ArcRwSignal<Url>, initial_url: &str) -> Self {
+		let initial = parse_url(initial_url);
+		let entries = Rc::new(RefCell::new(vec![location_string(&initial)]));
+		url.set(initial);
+		Self { url, entries }
+	}
+
+	/// Add the location the router navigated to.
+	fn push(&self, url: Option<String>) {
+		let location = self.resolve(url);
+		self.entries.borrow_mut().push(location);
+	}
+
+	/// Replace the current entry with the location the router navigated to.
+	fn replace(&self, url: Option<String>) {
+		let location = self.resolve(url);
+		if let Some(current) = self.entries.borrow_mut().last_mut() {
+			*current = location;
+		}
+	}
+
+	/// Remove the current entry and move the router back to the previous
+	/// one. Does nothing if there is only one entry.
+	fn back(&self) {
+		let previous = {
+			let mut entries = self.entries.borrow_mut();
+			if entries.len() < 2 {
+				return;
+			}
+			entries.pop();
+			entries.last().cloned()
+		};
+		if let Some(previous) = previous {
+			self.url.set(parse_url(&previous));
+		}
+	}
+
+	/// The location of `url` relative to the current one, which it stays at
+	/// when `url` is `None`, like `history.pushState` does.
+	fn resolve(&self, url: Option<String>) -> String {
+		let current = self.current();
+		url.map_or(current.clone(), |url| {
+			let base = format!("{}{current}", window().location().origin().unwrap());
+			BrowserUrl::parse_with_base(&url, &base).map_or(url, |url| location_string(&url))
+		})
+	}
+
+	fn current(&self) -> String {
+		self.url.with_untracked(location_string)
+	}
+
+	/// Record the `pushState` and `replaceState` calls the router makes to
+	/// complete a navigation, instead of changing the URL of the document.
+	/// The browser history is restored when the router is unmounted.
+	fn intercept_browser_history(&self) {
+		let browser_history = window().history().unwrap();
+		let push_state = {
+			let history = self.clone();
+			Closure::<dyn Fn(JsValue, JsValue, JsValue)>::new(move |_, _, url: JsValue| {
+				history.push(url.as_string());
+			})
+		};
+		let replace_state = {
+			let history = self.clone();
+			Closure::<dyn Fn(JsValue, JsValue, JsValue)>::new(move |_, _, url: JsValue| {
+				history.replace(url.as_string());
+			})
+		};
+		Reflect::set(&browser_history, &"pushState".into(), push_state.as_ref()).unwrap();
+		Reflect::set(
+			&browser_history,
+			&"replaceState".into(),
+			replace_state.as_ref(),
+		)
+		.unwrap();
+
+		let intercepted = SendWrapper::new((browser_history, push_state, replace_state));
+		Owner::on_cleanup(move || {
+			let (browser_history, ..) = intercepted.take();
+			Reflect::delete_property(&browser_history, &"pushState".into()).unwrap();
+			Reflect::delete_property(&browser_history, &"replaceState".into()).unwrap();
+		});
+	}
+}
+
+/// Parse `url` relative to the origin of the document.
+fn parse_url(url: &str) -> Url {
+	BrowserUrl::parse(url).unwrap_or_else(|error| panic!("{url} to be a valid URL: {error:?}"))
+}
+
+/// The path, query string and hash of `url`.
+fn location_string(url: &Url) -> String {
+	let search = match url.search() {
+		"" => String::new(),
+		search => format!("?{search}"),
+	};
+	format!("{}{search}{}", url.path(), url.hash())
+}
+
+/// Navigate in memory when a link in `container` is clicked, before the
+/// `<Router>` would navigate the document. Links that the browser would open
+/// elsewhere are left alone.
+fn handle_link_clicks(
+	container: &Element,
+	history: &MemoryHistory,
+	navigate: Navigate,
+) -> Closure<dyn Fn(MouseEvent)> {
+	let history = history.clone();
+	let closure = Closure::<dyn Fn(MouseEvent)>::new(move |event: MouseEvent| {
+		if event.default_prevented()
+			|| event.button() != 0
+			|| event.meta_key()
+			|| event.alt_key()
+			|| event.ctrl_key()
+			|| event.shift_key()
+		{
+			return;
+		}
+		let Some(link) = event
+			.target()
+			.and_then(|target| target.dyn_into::<Element>().ok())
+			.and_then(|target| target.closest("a[href]").ok().flatten())
+		else {
+			return;
+		};
+		let opens_elsewhere = link
+			.get_attribute("target")
+			.is_some_and(|target| !target.is_empty() && target != "_self")
+			|| link.has_attribute("download")
+			|| link
+				.get_attribute("rel")
+				.is_some_and(|rel| rel.split_whitespace().any(|rel| rel == "external"));
+		let href = link.get_attribute("href").unwrap_or_default();
+		let origin = window().location().origin().unwrap();
+		let base = format!("{origin}{}", history.current());
+		let Ok(url) = BrowserUrl::parse_with_base(&href, &base) else {
+			return;
+		};
+		if opens_elsewhere || url.origin() != origin {
+			return;
+		}
+
+		event.prevent_default();
+		navigate(
+			&location_string(&url),
+			NavigateOptions {
+				scroll: false,
+				..Default::default()
+			},
+		);
+	});
+	container
+		.add_event_listener_with_callback("click", closure.as_ref().unchecked_ref())
+		.unwrap();
+	closure
+}
+
+/// Routes rendered by [`render_with_router`]. Use the [`DomQuery`] methods to
+/// query them, and the methods of [`LeptosTestingLibraryRender`] through
+/// `Deref`.
+///
+/// [`DomQuery`]: crate::dom::DomQuery
+#[derive(Deref)]
+pub struct RouterRender<N: IntoView> {
+	#[deref]
+	render: LeptosTestingLibraryRender<N>,
+	history: MemoryHistory,
+	navigate: Navigate,
+	link_clicks: Closure<dyn Fn(MouseEvent)>,
+}
+
+impl<N: IntoView> RouterRender<N> {
+	/// Navigate to `path`, like `use_navigate` would, and wait for the DOM to
+	/// update. `path` is resolved against the current location, and is added
+	/// to [`RouterRender::history`].
+	pub async fn navigate(&self, path: &str) {
+		(self.navigate)(
+			path,
+			NavigateOptions {
+				scroll: false,
+				..Default::default()
+			},
+		);
+		tick().await;
+	}
+
+	/// Navigate back to the previous location in [`RouterRender::history`],
+	/// like the back button of the browser, and wait for the DOM to update.
+	/// Does nothing if the router hasn't navigated yet.
+	pub async fn go_back(&self) {
+		self.history.back();
+		tick().await;
+	}
+
+	/// The path, query string and hash the router is at, i.e
+	/// `/users/42?tab=posts`.
+	pub fn current_location(&self) -> String {
+		self.history.current()
+	}
+
+	/// Every location the router has been at, from the oldest to the current
+	/// one. [`RouterRender::go_back`] removes the current one.
+	pub fn history(&self) -> Vec<String> {
+		self.history.entries.borrow().clone()
+	}
+}
+
+impl<N: IntoView> Drop for RouterRender<N> {
+	fn drop(&mut self) {
+		_ = self.render.element().0.remove_event_listener_with_callback(
+			"click",
+			self.link_clicks.as_ref().unchecked_ref(),
+		);
+	}
+}
+
+impl<N: IntoView> HoldsElement for RouterRender<N> {
+	fn element(&self) -> ElementWrapper {
+		self.render.element()
+	}
+}
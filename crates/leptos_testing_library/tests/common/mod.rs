@@ -0,0 +1,17 @@
+use leptos_testing_library::prelude::*;
+
+/// Restores the global configuration when dropped, even if the test panics.
+pub struct RestoreConfig(Config);
+
+impl RestoreConfig {
+	pub fn new() -> Self {
+		Self(get_config())
+	}
+}
+
+impl Drop for RestoreConfig {
+	fn drop(&mut self) {
+		let config = self.0.clone();
+		configure(|current| *current = config);
+	}
+}
@@ -1,3 +1,4 @@
+use leptos_testing_library::dom::TestingLibraryError;
 use leptos_testing_library::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
@@ -6,6 +7,10 @@ use web_sys::HtmlInputElement;
 use web_sys::HtmlLabelElement;
 use web_sys::Node;
 
+use crate::common::RestoreConfig;
+
+mod common;
+
 wasm_bindgen_test_configure!(run_in_browser);
 
 #[wasm_bindgen_test]
@@ -146,6 +151,16 @@ pub fn by_text() {
 	assert!(renderer.get_by_text("hello_3").is_not_found());
 	assert_eq!(renderer.get_all_by_text_contains("hello").len(), 2);
 	assert_eq!(renderer.get_all_by_text("hello").len(), 1);
+
+	test_wrapper.set_inner_html("<p>Hello <b>world</b></p>");
+	assert!(renderer.get_by_text("Hello world").is_not_found());
+	assert_eq!(renderer.get_by_text("Hello").unwrap().tag_name(), "P");
+	assert_eq!(renderer.get_by_text("world").unwrap().tag_name(), "B");
+	let whole_text = TextMatch::predicate(|_, element: &web_sys::Element| {
+		element.text_content().as_deref() == Some("Hello world")
+	});
+	assert_eq!(renderer.get_by_text(whole_text).unwrap().tag_name(), "P");
+	test_wrapper.remove();
 }
 
 #[wasm_bindgen_test]
@@ -238,3 +253,240 @@ pub fn by_placeholder() {
 	let input = renderer.get_by_placeholder("Password").unwrap();
 	assert_eq!(input, TestElement(field.unchecked_into::<HtmlElement>()));
 }
+
+#[wasm_bindgen_test]
+pub fn by_test_id() {
+	let document = web_sys::window().unwrap().document().unwrap();
+	let body = document.body().unwrap();
+	let test_wrapper = document.create_element("div").unwrap();
+	let renderer = ElementWrapper(&test_wrapper);
+	body.append_child(test_wrapper.dyn_ref::<Node>().unwrap())
+		.unwrap();
+	let item_1 = document.create_element("li").unwrap();
+	item_1.set_attribute("data-testid", "todo-item-1").unwrap();
+	let item_2 = document.create_element("li").unwrap();
+	item_2.set_attribute("data-testid", "todo-item-2").unwrap();
+	item_2.set_attribute("data-qa", "todo-item").unwrap();
+	test_wrapper.append_child(&item_1).unwrap();
+	test_wrapper.append_child(&item_2).unwrap();
+
+	assert_eq!(
+		renderer
+			.get_by_test_id("todo-item-1")
+			.unwrap()
+			.0
+			.unchecked_into::<web_sys::Element>(),
+		item_1
+	);
+	assert!(renderer.get_by_test_id("todo-item").is_not_found());
+	assert!(
+		renderer
+			.get_by_test_id_contains("todo-item")
+			.is_more_than_one()
+	);
+	assert_eq!(renderer.get_all_by_test_id_contains("todo-item").len(), 2);
+
+	configure(|config| config.test_id_attribute = "data-qa".into());
+	let result = renderer.get_all_by_test_id("todo-item");
+	configure(|config| *config = Config::default());
+	assert_eq!(result.len(), 1);
+	assert_eq!(
+		result[0].0.clone().unchecked_into::<web_sys::Element>(),
+		item_2
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn query_by() {
+	let document = web_sys::window().unwrap().document().unwrap();
+	let body = document.body().unwrap();
+	let test_wrapper = document.create_element("div").unwrap();
+	let renderer = ElementWrapper(&test_wrapper);
+	body.append_child(test_wrapper.dyn_ref::<Node>().unwrap())
+		.unwrap();
+	let div = document.create_element("div").unwrap();
+	div.set_id("spinner");
+	test_wrapper.append_child(&div).unwrap();
+
+	assert_eq!(
+		renderer
+			.query_by_id("spinner")
+			.unwrap()
+			.unwrap()
+			.0
+			.unchecked_into::<web_sys::Element>(),
+		div
+	);
+	assert_eq!(renderer.query_by_id("error").unwrap(), None);
+	assert_eq!(renderer.query_by_text("Loading").unwrap(), None);
+	assert!(renderer.query_all_by_id_contains("error").is_empty());
+
+	let div_2 = document.create_element("div").unwrap();
+	div_2.set_id("spinner_2");
+	test_wrapper.append_child(&div_2).unwrap();
+	assert!(renderer.query_by_id_contains("spinner").is_more_than_one());
+	assert_eq!(renderer.query_all_by_id_contains("spinner").len(), 2);
+}
+
+#[wasm_bindgen_test]
+pub fn by_label_associations() {
+	let document = web_sys::window().unwrap().document().unwrap();
+	let body = document.body().unwrap();
+	let test_wrapper = document.create_element("div").unwrap();
+	let renderer = ElementWrapper(&test_wrapper);
+	body.append_child(test_wrapper.dyn_ref::<Node>().unwrap())
+		.unwrap();
+	test_wrapper.set_inner_html(
+		r#"
+		<h2>Email</h2>
+		<label>Email <input id="email" /></label>
+		<span id="first">First</span><span id="name">name</span>
+		<input id="first_name" aria-labelledby="first name" />
+		<input id="search" aria-label="Search" />
+		<label for="missing">Phone</label>
+		<label>Orphan</label>
+		"#,
+	);
+
+	assert_eq!(renderer.get_by_label("Email").unwrap().id(), "email");
+	assert_eq!(
+		renderer.get_by_label("First name").unwrap().id(),
+		"first_name"
+	);
+	assert_eq!(renderer.get_by_label("name").unwrap().id(), "first_name");
+	assert_eq!(renderer.get_by_label("Search").unwrap().id(), "search");
+	assert_eq!(
+		renderer.get_by_label("Phone"),
+		Err(TestingLibraryError::LabelWithoutControl {
+			label: "Phone".into()
+		})
+	);
+	assert!(renderer.get_all_by_label("Orphan").is_empty());
+	assert!(matches!(
+		renderer.get_by_label("Orphan"),
+		Err(TestingLibraryError::LabelWithoutControl { .. })
+	));
+	assert!(renderer.get_by_label("Nothing").is_not_found());
+	test_wrapper.remove();
+}
+
+#[wasm_bindgen_test]
+pub fn by_alt_text_and_title() {
+	let document = web_sys::window().unwrap().document().unwrap();
+	let body = document.body().unwrap();
+	let test_wrapper = document.create_element("div").unwrap();
+	let renderer = ElementWrapper(&test_wrapper);
+	body.append_child(test_wrapper.dyn_ref::<Node>().unwrap())
+		.unwrap();
+	test_wrapper.set_inner_html(
+		r#"
+		<img id="cat" alt="A sleeping cat" src="" />
+		<input id="submit" type="image" alt="Submit order" />
+		<map><area id="area" alt="A sleeping dog" href="/" /></map>
+		<div id="not_an_image" alt="A sleeping cat"></div>
+		<button id="close" title="Close dialog">x</button>
+		<span id="icon"><svg><title>Delete item</title></svg></span>
+		<svg><rect title="Draft"></rect></svg>
+		"#,
+	);
+
+	assert_eq!(
+		renderer.get_by_alt_text("A sleeping cat").unwrap().id(),
+		"cat"
+	);
+	assert_eq!(
+		renderer.get_by_alt_text("Submit order").unwrap().id(),
+		"submit"
+	);
+	assert!(
+		renderer
+			.get_by_alt_text_contains("sleeping")
+			.is_more_than_one()
+	);
+	assert_eq!(renderer.get_all_by_alt_text_contains("sleeping").len(), 2);
+	assert!(renderer.query_by_alt_text("Nothing").unwrap().is_none());
+
+	assert_eq!(renderer.get_by_title("Close dialog").unwrap().id(), "close");
+	assert_eq!(
+		renderer.get_by_title_contains("Delete").unwrap().id(),
+		"icon"
+	);
+	assert!(renderer.get_by_title("Draft").is_not_found());
+	assert_eq!(renderer.get_all_by_title_contains("e").len(), 2);
+	assert!(renderer.get_by_title("close dialog").is_not_found());
+	test_wrapper.remove();
+}
+
+#[wasm_bindgen_test]
+pub fn error_details() {
+	let document = web_sys::window().unwrap().document().unwrap();
+	let test_wrapper = document.create_element("div").unwrap();
+	document
+		.body()
+		.unwrap()
+		.append_child(&test_wrapper)
+		.unwrap();
+	test_wrapper.set_inner_html(
+		"<form><!-- marker --><p>Email</p><input placeholder=\"Search\" \
+		 /><button>Submit</button><button>Cancel</button></form>",
+	);
+	let renderer = ElementWrapper(&test_wrapper);
+
+	let Err(TestingLibraryError::NotFound {
+		dom, suggestions, ..
+	}) = renderer.get_by_text("Sbumit")
+	else {
+		panic!("expected a NotFound error");
+	};
+	assert_eq!(
+		dom,
+		"<div>\n  <form>\n    <p>\n      Email\n    </p>\n    <input placeholder=\"Search\">\n    \
+		 <button>\n      Submit\n    </button>\n    <button>\n      Cancel\n    </button>\n  \
+		 </form>\n</div>"
+	);
+	assert_eq!(suggestions, vec![r#"Did you mean "Submit"?"#.to_string()]);
+
+	let Err(error @ TestingLibraryError::NotFound { .. }) = renderer.get_by_label("Email") else {
+		panic!("expected a NotFound error");
+	};
+	assert!(error.to_string().contains(
+		"An element with the text \"Email\" exists, but it isn't a <label> and no element refers \
+		 to it with aria-labelledby: <p>."
+	));
+	let Err(TestingLibraryError::NotFound { suggestions, .. }) = renderer.get_by_text("Search")
+	else {
+		panic!("expected a NotFound error");
+	};
+	assert_eq!(
+		suggestions,
+		vec![r#"get_by_placeholder("Search") would find the <input> element instead."#.to_string()]
+	);
+
+	let Err(TestingLibraryError::MoreThanOne { matches, .. }) = renderer.get_by_role("button")
+	else {
+		panic!("expected a MoreThanOne error");
+	};
+	assert_eq!(
+		matches,
+		vec![
+			"<button>\n  Submit\n</button>".to_string(),
+			"<button>\n  Cancel\n</button>".to_string()
+		]
+	);
+	let Err(TestingLibraryError::MoreThanOne { matches, dom, .. }) =
+		renderer.query_by_role("button")
+	else {
+		panic!("expected a MoreThanOne error");
+	};
+	assert_eq!(matches.len(), 2);
+	assert!(dom.contains("<form>"));
+	assert_eq!(renderer.query_by_text("Sbumit").unwrap(), None);
+
+	let _config = RestoreConfig::new();
+	configure(|config| config.max_dom_length = 10);
+	let Err(TestingLibraryError::NotFound { dom, .. }) = renderer.get_by_text("Nothing") else {
+		panic!("expected a NotFound error");
+	};
+	assert_eq!(dom, "<div>\n  <fo...");
+	test_wrapper.remove();
+}
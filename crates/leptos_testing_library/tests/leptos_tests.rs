@@ -1,7 +1,28 @@
+use std::time::Duration;
+
+use leptos::portal::Portal;
 use leptos::prelude::*;
 use leptos::task::tick;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use leptos_router::components::Route;
+use leptos_router::components::Routes;
+use leptos_router::hooks::use_params_map;
+use leptos_router::hooks::use_query_map;
+use leptos_router::path;
+use leptos_testing_library::dom::QueryKind;
+use leptos_testing_library::dom::SuggestedQuery;
+use leptos_testing_library::dom::TestingLibraryError;
+use leptos_testing_library::dom::user_event::UploadFile;
 use leptos_testing_library::prelude::*;
+use regex::Regex;
+use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
+use web_sys::Element;
+
+use crate::common::RestoreConfig;
+
+mod common;
 
 wasm_bindgen_test_configure!(run_in_browser);
 
@@ -73,3 +94,1243 @@ pub fn iterate_list() {
 
 	assert_eq!(questions, String::from("Hi how are you ?"));
 }
+
+#[wasm_bindgen_test]
+pub async fn find_by_text_waits_for_content() {
+	let render = render_for_test(|| {
+		let loaded = RwSignal::new(false);
+		set_timeout(move || loaded.set(true), Duration::from_millis(100));
+		view! {
+			<Show when=move || loaded.get() fallback=|| view! { <p>"Loading..."</p> }>
+				<p>"Loaded"</p>
+			</Show>
+		}
+	});
+
+	assert!(render.query_by_text("Loaded").unwrap().is_none());
+	render.find_by_text("Loaded").await.unwrap();
+	assert!(render.query_by_text("Loading...").unwrap().is_none());
+	assert_eq!(render.find_all_by_text("Loaded").await.unwrap().len(), 1);
+}
+
+#[wasm_bindgen_test]
+pub async fn find_by_times_out() {
+	configure(|config| config.async_util_timeout = Duration::from_millis(100));
+	let render = render_for_test(|| view! { <p>"Hello"</p> });
+	let result = render.find_by_text("Goodbye").await;
+	let all_result = render.find_all_by_text("Goodbye").await;
+	configure(|config| *config = Config::default());
+
+	match result {
+		Err(TestingLibraryError::Timeout { last_error, .. }) => {
+			assert!(last_error.is_not_found());
+		}
+		other => panic!("Expected a timeout, got {other:?}"),
+	}
+	assert!(all_result.is_timeout());
+}
+
+#[wasm_bindgen_test]
+pub async fn wait_for_builds_error_details_for_the_last_attempt() {
+	let render = render_for_test(|| view! { <button>"Submit"</button> });
+	let mut attempts = Vec::new();
+	let result = wait_for_with_options(
+		|| {
+			let result = render.get_by_text("Sbumit");
+			if let Err(TestingLibraryError::NotFound {
+				dom, suggestions, ..
+			}) = &result
+			{
+				attempts.push((dom.clone(), suggestions.clone()));
+			}
+			result
+		},
+		WaitForOptions {
+			timeout: Duration::from_millis(100),
+			interval: Duration::from_millis(10),
+		},
+	)
+	.await;
+	assert!(result.is_not_found());
+
+	let ((dom, suggestions), earlier) = attempts.split_last().unwrap();
+	assert!(dom.contains("Submit"));
+	assert_eq!(suggestions, &[r#"Did you mean "Submit"?"#.to_string()]);
+	assert!(!earlier.is_empty());
+	assert!(
+		earlier
+			.iter()
+			.all(|(dom, suggestions)| dom.is_empty() && suggestions.is_empty())
+	);
+}
+
+#[wasm_bindgen_test]
+pub async fn wait_for_resource() {
+	let render = render_for_test(|| {
+		let count = LocalResource::new(|| async { 42 });
+		view! {
+			<Suspense fallback=|| view! { <p id="spinner">"Loading..."</p> }>
+				<span id="count">{move || Suspend::new(async move { count.await })}</span>
+			</Suspense>
+		}
+	});
+
+	let spinner = render.get_by_id("spinner").unwrap();
+	wait_for_element_to_be_removed(&spinner).await.unwrap();
+
+	let count = wait_for(|| {
+		match render
+			.get_by_id("count")
+			.map(|element| element.parse::<usize>())
+		{
+			Ok(Ok(count)) => Ok(count),
+			_ => Err("The count hasn't loaded yet"),
+		}
+	})
+	.await
+	.unwrap();
+	assert_eq!(count, 42);
+	let error = wait_for_element_to_be_removed(&render.get_by_id("count").unwrap())
+		.await
+		.unwrap_err();
+	assert!(error.is_timeout());
+	assert!(
+		error
+			.to_string()
+			.contains("waiting for the element to be removed")
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn by_implicit_role() {
+	let render = render_for_test(|| {
+		view! {
+			<header>
+				<nav>
+					<a href="/">"Home"</a>
+					<a>"Not a link"</a>
+				</nav>
+			</header>
+			<main>
+				<h1>"Title"</h1>
+				<h2>"Subtitle"</h2>
+				<ul>
+					<li>"One"</li>
+					<li>"Two"</li>
+				</ul>
+				<input type="checkbox" />
+				<input type="text" />
+				<input list="suggestions" />
+				<select>
+					<option>"A"</option>
+				</select>
+				<button>"Save"</button>
+				<article>
+					<footer>"Article footer"</footer>
+				</article>
+			</main>
+			<footer>"Footer"</footer>
+		}
+	});
+
+	assert_eq!(render.get_by_role("button").unwrap().inner_text(), "Save");
+	assert_eq!(render.get_by_role("link").unwrap().inner_text(), "Home");
+	assert!(render.get_by_role("checkbox").is_ok());
+	assert!(render.get_by_role("textbox").is_ok());
+	assert_eq!(render.get_all_by_role("combobox").len(), 2);
+	assert_eq!(render.get_all_by_role("heading").len(), 2);
+	assert!(render.get_by_role("list").is_ok());
+	assert_eq!(render.get_all_by_role("listitem").len(), 2);
+	assert!(render.get_by_role("banner").is_ok());
+	assert!(render.get_by_role("navigation").is_ok());
+	assert!(render.get_by_role("main").is_ok());
+	assert_eq!(
+		render.get_by_role("contentinfo").unwrap().inner_text(),
+		"Footer"
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn by_role_with_options() {
+	let render = render_for_test(|| {
+		view! {
+			<h1>"Settings"</h1>
+			<h2>"Profile"</h2>
+			<h2>"Security"</h2>
+			<button>"Save"</button>
+			<button aria-label="Close dialog">"x"</button>
+			<button aria-pressed="true">"Bold"</button>
+			<button aria-expanded="false">"Menu"</button>
+			<button hidden>"Hidden"</button>
+			<div aria-hidden="true">
+				<button>"Also hidden"</button>
+			</div>
+			<input type="checkbox" checked />
+			<input type="checkbox" />
+			<input type="text" aria-label="Search" />
+			<select>
+				<option>"One"</option>
+				<option selected>"Two"</option>
+			</select>
+		}
+	});
+
+	let save = render
+		.get_by_role_with("button", ByRoleOptions::default().name("Save"))
+		.unwrap();
+	assert_eq!(save.inner_text(), "Save");
+	assert!(
+		render
+			.get_by_role_with("button", ByRoleOptions::default().name("Close dialog"))
+			.is_ok()
+	);
+	assert!(
+		render
+			.get_by_role_with(
+				"button",
+				ByRoleOptions::default().name(|name: &str, _: &Element| name.starts_with("Sa"))
+			)
+			.is_ok()
+	);
+	assert_eq!(
+		render
+			.get_all_by_role_with("heading", ByRoleOptions::default().level(2))
+			.len(),
+		2
+	);
+	assert!(
+		render
+			.get_by_role_with("button", ByRoleOptions::default().pressed(true))
+			.is_ok()
+	);
+	assert!(
+		render
+			.get_by_role_with("button", ByRoleOptions::default().expanded(false))
+			.is_ok()
+	);
+	assert!(
+		render
+			.get_by_role_with("button", ByRoleOptions::default().pressed(false))
+			.is_not_found()
+	);
+	assert!(
+		render
+			.get_by_role_with("checkbox", ByRoleOptions::default().checked(true))
+			.is_ok()
+	);
+	assert!(
+		render
+			.get_by_role_with("checkbox", ByRoleOptions::default().checked(false))
+			.is_ok()
+	);
+	assert!(
+		render
+			.get_by_role_with("textbox", ByRoleOptions::default().checked(false))
+			.is_not_found()
+	);
+	assert_eq!(
+		render
+			.get_by_role_with("option", ByRoleOptions::default().selected(true))
+			.unwrap()
+			.inner_text(),
+		"Two"
+	);
+	assert_eq!(render.get_all_by_role("button").len(), 4);
+	assert_eq!(
+		render
+			.get_all_by_role_with("button", ByRoleOptions::default().hidden(true))
+			.len(),
+		6
+	);
+	assert!(
+		render
+			.query_by_role_with("button", ByRoleOptions::default().name("Delete"))
+			.unwrap()
+			.is_none()
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn accessible_name_and_description() {
+	let render = render_for_test(|| {
+		view! {
+			<span id="first">"Billing"</span>
+			<span id="second">"address"</span>
+			<span id="hint">"We never share it"</span>
+			<input id="labelled_by" aria-labelledby="first second" aria-describedby="hint" />
+			<input id="aria_label" aria-label="Search" />
+			<label for="for_label">"Email"</label>
+			<input id="for_label" />
+			<label>"Phone" <input id="wrapped" /></label>
+			<img id="image" alt="Company logo" />
+			<button id="content">
+				<span>"Save"</span>
+				<span hidden>"Hidden"</span>
+				" draft"
+			</button>
+			<button id="titled" title="Close">"x"</button>
+			<input id="placeholder" placeholder="Username" />
+			<input id="submit" type="submit" />
+			<fieldset id="fieldset">
+				<legend>"Shipping"</legend>
+			</fieldset>
+		}
+	});
+
+	let name = |id| render.get_by_id(id).unwrap().accessible_name();
+	assert_eq!(name("labelled_by"), "Billing address");
+	assert_eq!(name("aria_label"), "Search");
+	assert_eq!(name("for_label"), "Email");
+	assert_eq!(name("wrapped"), "Phone");
+	assert_eq!(name("image"), "Company logo");
+	assert_eq!(name("content"), "Save draft");
+	assert_eq!(name("titled"), "x");
+	assert_eq!(name("placeholder"), "Username");
+	assert_eq!(name("submit"), "Submit");
+	assert_eq!(name("fieldset"), "Shipping");
+
+	let description = |id| render.get_by_id(id).unwrap().accessible_description();
+	assert_eq!(description("labelled_by"), "We never share it");
+	assert_eq!(description("titled"), "Close");
+	assert_eq!(description("image"), "");
+}
+
+#[wasm_bindgen_test]
+pub fn text_match() {
+	let render = render_for_test(|| {
+		let count = RwSignal::new(3);
+		view! {
+			<h1>"  Shopping   list  "</h1>
+			<p id="count">"Items: " {move || count.get()}</p>
+			<button>"Add item"</button>
+			<button>"Remove item"</button>
+		}
+	});
+
+	assert!(render.get_by_text("Shopping list").is_ok());
+	assert!(render.get_by_text("Items: 3").is_ok());
+	assert!(
+		render
+			.get_by_text(TextMatch::exact("shopping LIST").ignore_case())
+			.is_ok()
+	);
+	assert!(render.get_by_text("shopping list").is_not_found());
+	assert_eq!(
+		render
+			.get_all_by_text(Regex::new(r"^(Add|Remove) item$").unwrap())
+			.len(),
+		2
+	);
+	assert_eq!(
+		render
+			.get_by_text(|text: &str, element: &Element| {
+				element.tag_name() == "BUTTON" && text.starts_with("Add")
+			})
+			.unwrap()
+			.inner_text(),
+		"Add item"
+	);
+	assert!(render.get_by_text_contains("item").is_more_than_one());
+	assert!(render.get_by_id(Regex::new("^co").unwrap()).is_ok());
+	assert!(
+		render
+			.get_by_text(TextMatch::exact("  Shopping   list  ").normalizer(str::to_string))
+			.is_ok()
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn scoped_queries() {
+	let render = render_for_test(|| {
+		view! {
+			<article data-testid="card">
+				<h2>"First"</h2>
+				<button>"Open"</button>
+			</article>
+			<article data-testid="card">
+				<h2>"Second"</h2>
+				<button>"Open"</button>
+			</article>
+			<div role="dialog">
+				<p>"Are you sure?"</p>
+				<button>"Confirm"</button>
+			</div>
+		}
+	});
+
+	assert!(render.get_by_text("Open").is_more_than_one());
+	let cards = render.get_all_by_test_id("card");
+	assert_eq!(cards.len(), 2);
+	assert_eq!(
+		within(&cards[1])
+			.get_by_role("heading")
+			.unwrap()
+			.inner_text(),
+		"Second"
+	);
+	assert!(within(&cards[0]).get_by_text("Open").is_ok());
+	assert!(within(&cards[0]).get_by_text("Second").is_not_found());
+
+	let dialog = render.get_by_role("dialog").unwrap();
+	assert!(dialog.get_by_text("Confirm").is_ok());
+	assert!(dialog.get_by_text("Open").is_not_found());
+	assert_eq!(
+		render
+			.get_by_role("dialog")
+			.and_then(|dialog| dialog.get_by_role("button"))
+			.unwrap()
+			.inner_text(),
+		"Confirm"
+	);
+}
+
+#[wasm_bindgen_test]
+pub async fn screen_finds_portal_content() {
+	let render = render_for_test(|| {
+		view! {
+			<p>"Page"</p>
+			<Portal>
+				<div role="alertdialog">"Saved!"</div>
+			</Portal>
+		}
+	});
+
+	let toast = screen().find_by_role("alertdialog").await.unwrap();
+	assert_eq!(toast.inner_text(), "Saved!");
+	assert!(render.get_by_text("Saved!").is_not_found());
+	assert!(screen().get_by_text("Page").is_ok());
+}
+
+#[wasm_bindgen_test]
+pub fn render_cleanup() {
+	let cleaned_up = RwSignal::new(false);
+	let render = render_for_test(move || {
+		Owner::on_cleanup(move || cleaned_up.set(true));
+		view! { <p>"Mounted"</p> }
+	});
+	assert!(screen().get_by_text("Mounted").is_ok());
+
+	render.unmount();
+	assert!(cleaned_up.get_untracked());
+	assert!(render.get_by_text("Mounted").is_not_found());
+	let container = render.element().0.clone();
+	assert!(container.is_connected());
+
+	drop(render);
+	assert!(!container.is_connected());
+
+	let first = render_for_test(|| view! { <p>"First"</p> });
+	let second = render_for_test(|| view! { <p>"Second"</p> });
+	cleanup();
+	assert!(screen().get_by_text("First").is_not_found());
+	assert!(screen().get_by_text("Second").is_not_found());
+	drop((first, second));
+}
+
+#[wasm_bindgen_test]
+pub async fn rerender_with_props() {
+	let cleanups = RwSignal::new(0);
+	let render = render_for_test_with_props("Alice".to_string(), move |name| {
+		Effect::new(move |_| {
+			name.track();
+			Owner::on_cleanup(move || cleanups.update(|count| *count += 1));
+		});
+		view! { <p id="greeting">"Hello " {name}</p> }
+	});
+	tick().await;
+	let greeting = render.get_by_id("greeting").unwrap();
+	assert_eq!(greeting.display_text(), "Hello Alice");
+
+	render.rerender("Bob".to_string()).await;
+	assert_eq!(greeting.display_text(), "Hello Bob");
+	assert_eq!(render.get_by_id("greeting").unwrap(), greeting);
+	assert_eq!(cleanups.get_untracked(), 1);
+
+	let render = render_for_test_with_props_and_options(
+		"Alice".to_string(),
+		|name| {
+			let theme = expect_context::<Theme>();
+			view! { <p>"Hello " {name} " in the " {theme.0} " theme"</p> }
+		},
+		RenderOptions::default().with_context(Theme("dark")),
+	);
+	assert!(render.get_by_text("Hello Alice in the dark theme").is_ok());
+	render.rerender("Bob".to_string()).await;
+	assert!(render.get_by_text("Hello Bob in the dark theme").is_ok());
+}
+
+#[wasm_bindgen_test]
+pub async fn render_with_base_element() {
+	let base_element = document().create_element("section").unwrap();
+	document()
+		.body()
+		.unwrap()
+		.append_child(&base_element)
+		.unwrap();
+	let mount = base_element.clone();
+	let render = render_for_test_with(
+		move || {
+			view! {
+				<p>"Inside"</p>
+				<Portal mount=mount>
+					<p>"Beside"</p>
+				</Portal>
+			}
+		},
+		RenderOptions {
+			base_element: Some(base_element.clone().unchecked_into()),
+			..Default::default()
+		},
+	);
+
+	assert!(render.base_element().find_by_text("Beside").await.is_ok());
+	assert!(render.base_element().get_by_text("Inside").is_ok());
+	assert!(render.get_by_text("Beside").is_not_found());
+	assert!(
+		within(&TestElement(base_element.unchecked_into()))
+			.get_by_text("Inside")
+			.is_ok()
+	);
+}
+
+#[derive(Clone)]
+struct Theme(&'static str);
+
+#[derive(Clone)]
+struct User(&'static str);
+
+#[wasm_bindgen_test]
+pub fn render_with_wrapper() {
+	let options = RenderOptions::default()
+		.with_context(Theme("dark"))
+		.with_wrapper(|children| {
+			let theme = expect_context::<Theme>();
+			provide_context(User("Ada"));
+			view! { <main class=theme.0>{children()}</main> }.into_any()
+		})
+		.with_url("/users/42?tab=posts");
+	let render = render_for_test_with(
+		|| {
+			let user = expect_context::<User>();
+			let theme = expect_context::<Theme>();
+			view! { <p>{user.0} " uses the " {theme.0} " theme"</p> }
+		},
+		options,
+	);
+
+	assert!(render.get_by_text("Ada uses the dark theme").is_ok());
+	assert_eq!(render.get_by_role("main").unwrap().class_name(), "dark");
+	let location = window().location();
+	assert_eq!(location.pathname().unwrap(), "/users/42");
+	assert_eq!(location.search().unwrap(), "?tab=posts");
+
+	let container = document().create_element("article").unwrap();
+	document().body().unwrap().append_child(&container).unwrap();
+	let render = render_for_test_with(
+		|| view! { <p>"In the article"</p> },
+		RenderOptions {
+			container: Some(container.clone().unchecked_into()),
+			..Default::default()
+		},
+	);
+	assert!(
+		within(&TestElement(container.clone().unchecked_into()))
+			.get_by_text("In the article")
+			.is_ok()
+	);
+	assert!(render.base_element().get_by_text("In the article").is_ok());
+
+	drop(render);
+	assert!(container.is_connected());
+	assert_eq!(container.child_element_count(), 0);
+	container.remove();
+}
+
+#[wasm_bindgen_test]
+pub async fn render_with_router_and_meta() {
+	let title = document().title();
+	let render = render_for_test_with(
+		|| {
+			view! {
+				<Title text="Profile" />
+				<Routes fallback=|| "Not found">
+					<Route
+						path=path!("/users/:id")
+						view=|| {
+							let params = use_params_map();
+							view! { <p>"User " {move || params.read().get("id")}</p> }
+						}
+					/>
+				</Routes>
+			}
+		},
+		RenderOptions::default()
+			.with_router("/users/42")
+			.with_meta(),
+	);
+	tick().await;
+
+	assert!(render.get_by_text("User 42").is_ok());
+	assert_eq!(document().title(), "Profile");
+	assert_eq!(window().location().pathname().unwrap(), "/users/42");
+
+	drop(render);
+	document().set_title(&title);
+}
+
+#[wasm_bindgen_test]
+pub async fn render_with_router_navigates() {
+	let browser_history = window().history().unwrap();
+	let history_length = browser_history.length().unwrap();
+	let href = window().location().href().unwrap();
+
+	let render = render_with_router(
+		|| {
+			view! {
+				<A href="/users/7?tab=posts">"Posts of user 7"</A>
+				<Routes fallback=|| "Not found">
+					<Route
+						path=path!("/users/:id")
+						view=|| {
+							let params = use_params_map();
+							let query = use_query_map();
+							view! {
+								<p>"User " {move || params.read().get("id")}</p>
+								<p>"Tab " {move || query.read().get("tab")}</p>
+							}
+						}
+					/>
+				</Routes>
+			}
+		},
+		"/users/42",
+	);
+	tick().await;
+	assert!(render.get_by_text("User 42").is_ok());
+	assert_eq!(render.current_location(), "/users/42");
+	assert_eq!(render.history(), ["/users/42"]);
+
+	render.get_by_text("Posts of user 7").unwrap().click();
+	tick().await;
+	assert!(render.get_by_text("User 7").is_ok());
+	assert!(render.get_by_text("Tab posts").is_ok());
+	assert_eq!(render.current_location(), "/users/7?tab=posts");
+	assert_eq!(browser_history.length().unwrap(), history_length);
+	assert_eq!(window().location().href().unwrap(), href);
+
+	render.navigate("/users/3").await;
+	assert!(render.get_by_text("User 3").is_ok());
+	assert!(render.get_by_text("Tab").is_ok());
+	assert_eq!(
+		render.history(),
+		["/users/42", "/users/7?tab=posts", "/users/3"]
+	);
+
+	render.go_back().await;
+	assert!(render.get_by_text("User 7").is_ok());
+	assert_eq!(render.current_location(), "/users/7?tab=posts");
+	assert_eq!(render.history(), ["/users/42", "/users/7?tab=posts"]);
+
+	render.go_back().await;
+	assert!(render.get_by_text("User 42").is_ok());
+	render.go_back().await;
+	assert_eq!(render.history(), ["/users/42"]);
+	assert_eq!(browser_history.length().unwrap(), history_length);
+	assert_eq!(window().location().href().unwrap(), href);
+}
+
+#[wasm_bindgen_test]
+pub fn render_with_url_restores_location() {
+	let location = window().location();
+	let url = location.href().unwrap();
+	let render = render_for_test_with(
+		|| view! { <a href="/settings">"Settings"</a> },
+		RenderOptions::default().with_url("/users/42"),
+	);
+	assert_eq!(location.pathname().unwrap(), "/users/42");
+
+	window()
+		.history()
+		.unwrap()
+		.push_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some("/settings"))
+		.unwrap();
+	assert_eq!(location.pathname().unwrap(), "/settings");
+
+	drop(render);
+	assert_eq!(location.href().unwrap(), url);
+}
+
+#[wasm_bindgen_test]
+pub async fn fire_events() {
+	let render = render_for_test(|| {
+		let name = RwSignal::new(String::new());
+		let last_key = RwSignal::new(String::new());
+		let focused = RwSignal::new(false);
+		let scrolled = RwSignal::new(0.0);
+		view! {
+			<form on:submit=|event| event.prevent_default()>
+				<input
+					on:input=move |event| name.set(event_target_value(&event))
+					on:keydown=move |event| {
+						let shift = if event.shift_key() { "Shift+" } else { "" };
+						last_key.set(format!("{shift}{}", event.key()));
+					}
+					on:focus=move |_| focused.set(true)
+					on:blur=move |_| focused.set(false)
+					on:wheel=move |event| scrolled.update(|value| *value += event.delta_y())
+				/>
+			</form>
+			<p id="name">{name}</p>
+			<p id="key">{last_key}</p>
+			<p id="focused">{move || focused.get().to_string()}</p>
+			<p id="scrolled">{scrolled}</p>
+		}
+	});
+	let text = |id: &str| render.get_by_id(id).unwrap().display_text();
+	let input = render.get_by_role("textbox").unwrap();
+
+	input.set_display_value("Leptos");
+	tick().await;
+	assert_eq!(text("name"), "Leptos");
+
+	assert!(!fire_event::focus(&input));
+	tick().await;
+	assert_eq!(text("focused"), "true");
+	fire_event::blur(&input);
+	tick().await;
+	assert_eq!(text("focused"), "false");
+
+	fire_event::key_down_with(
+		&input,
+		EventOptions {
+			key: "A".into(),
+			code: "KeyA".into(),
+			shift_key: true,
+			..Default::default()
+		},
+	);
+	tick().await;
+	assert_eq!(text("key"), "Shift+A");
+
+	fire_event::wheel_with(
+		&input,
+		EventOptions {
+			delta_y: 120.0,
+			..Default::default()
+		},
+	);
+	tick().await;
+	assert_eq!(text("scrolled"), "120");
+
+	let form = input.parent_element().unwrap();
+	assert!(fire_event::submit(&form));
+	assert!(!fire_event::submit_with(
+		&form,
+		EventOptions {
+			cancelable: Some(false),
+			..Default::default()
+		}
+	));
+}
+
+#[wasm_bindgen_test]
+pub async fn user_event_keyboard() {
+	let render = render_for_test(|| {
+		let name = RwSignal::new(String::new());
+		let submitted = RwSignal::new(String::new());
+		view! {
+			<form on:submit=move |event| {
+				event.prevent_default();
+				submitted.set(name.get_untracked());
+			}>
+				<input aria-label="Name" on:input=move |event| name.set(event_target_value(&event)) />
+				<textarea aria-label="Notes"></textarea>
+				<input aria-label="Disabled" disabled />
+			</form>
+			<p id="name">{name}</p>
+			<p id="submitted">{submitted}</p>
+		}
+	});
+	let text = |id: &str| render.get_by_id(id).unwrap().display_text();
+	let name = render.get_by_label("Name").unwrap();
+	let notes = render.get_by_label("Notes").unwrap();
+
+	user_event::type_text(&name, "Lepto").unwrap();
+	tick().await;
+	assert_eq!(text("name"), "Lepto");
+	user_event::keyboard("s{ArrowLeft}{Backspace}").unwrap();
+	tick().await;
+	assert_eq!(text("name"), "Lepts");
+	user_event::clear(&name).unwrap();
+	tick().await;
+	assert_eq!(text("name"), "");
+	user_event::type_text(&name, "{Shift>}ab{/Shift}{Enter}").unwrap();
+	tick().await;
+	assert_eq!(text("submitted"), "ab");
+
+	user_event::type_text(&notes, "one{Enter}two").unwrap();
+	assert_eq!(
+		notes
+			.unchecked_ref::<web_sys::HtmlTextAreaElement>()
+			.value(),
+		"one\ntwo"
+	);
+	user_event::paste(" three");
+	assert_eq!(
+		notes
+			.unchecked_ref::<web_sys::HtmlTextAreaElement>()
+			.value(),
+		"one\ntwo three"
+	);
+
+	user_event::shift_tab();
+	assert_eq!(document().active_element().unwrap(), **name);
+	user_event::tab();
+	assert_eq!(document().active_element().unwrap(), **notes);
+
+	let disabled = render.get_by_label("Disabled").unwrap();
+	assert!(matches!(
+		user_event::clear(&disabled),
+		Err(TestingLibraryError::NotEditable { .. })
+	));
+	assert!(matches!(
+		user_event::type_text(&disabled, "ignored{Enter}"),
+		Err(TestingLibraryError::NotEditable { .. })
+	));
+	assert_eq!(document().active_element().unwrap(), **notes);
+	assert_eq!(text("submitted"), "ab");
+	assert!(matches!(
+		user_event::keyboard("{Enter"),
+		Err(TestingLibraryError::InvalidKeyboardInput { .. })
+	));
+}
+
+#[wasm_bindgen_test]
+pub async fn user_event_pointer() {
+	let render = render_for_test(|| {
+		let clicks = RwSignal::new(0);
+		let hovered = RwSignal::new(false);
+		let fruits = RwSignal::new(Vec::<String>::new());
+		view! {
+			<button
+				on:click=move |_| clicks.update(|clicks| *clicks += 1)
+				on:mouseenter=move |_| hovered.set(true)
+				on:mouseleave=move |_| hovered.set(false)
+			>
+				"Count"
+			</button>
+			<button disabled on:click=move |_| clicks.update(|clicks| *clicks += 100)>
+				"Disabled"
+			</button>
+			<button style="pointer-events: none">"Hidden"</button>
+			<select
+				multiple
+				aria-label="Fruits"
+				on:change=move |event| {
+					let select = event_target::<web_sys::HtmlSelectElement>(&event);
+					let options = select.selected_options();
+					fruits
+						.set(
+							(0..options.length())
+								.filter_map(|i| options.item(i))
+								.map(|option| option.text_content().unwrap_or_default())
+								.collect(),
+						);
+				}
+			>
+				<option value="apple">"Apple"</option>
+				<option value="banana">"Banana"</option>
+				<option value="cherry">"Cherry"</option>
+			</select>
+			<p id="clicks">{clicks}</p>
+			<p id="hovered">{move || hovered.get().to_string()}</p>
+			<p id="fruits">{move || fruits.get().join(", ")}</p>
+		}
+	});
+	let text = |id: &str| render.get_by_id(id).unwrap().display_text();
+	let button = render.get_by_text("Count").unwrap();
+
+	user_event::hover(&button).unwrap();
+	tick().await;
+	assert_eq!(text("hovered"), "true");
+	user_event::click(&button).unwrap();
+	tick().await;
+	assert_eq!(text("clicks"), "1");
+	assert_eq!(document().active_element().unwrap(), **button);
+	user_event::dbl_click(&button).unwrap();
+	tick().await;
+	assert_eq!(text("clicks"), "3");
+	user_event::unhover(&button).unwrap();
+	tick().await;
+	assert_eq!(text("hovered"), "false");
+
+	user_event::click(&render.get_by_text("Disabled").unwrap()).unwrap();
+	tick().await;
+	assert_eq!(text("clicks"), "3");
+	assert!(matches!(
+		user_event::click(&render.get_by_text("Hidden").unwrap()),
+		Err(TestingLibraryError::PointerEventsDisabled { .. })
+	));
+
+	let fruits = render.get_by_label("Fruits").unwrap();
+	user_event::select_options(&fruits, &["apple", "Cherry"]).unwrap();
+	tick().await;
+	assert_eq!(text("fruits"), "Apple, Cherry");
+	user_event::deselect_options(&fruits, &["Apple"]).unwrap();
+	tick().await;
+	assert_eq!(text("fruits"), "Cherry");
+	assert!(user_event::select_options(&fruits, &["durian"]).is_not_found());
+}
+
+#[wasm_bindgen_test]
+pub async fn user_event_upload() {
+	let render = render_for_test(|| {
+		let names = RwSignal::new(String::new());
+		let on_change = move |event: leptos::ev::Event| {
+			let files = event_target::<web_sys::HtmlInputElement>(&event)
+				.files()
+				.unwrap();
+			names.set(
+				(0..files.length())
+					.filter_map(|i| files.item(i))
+					.map(|file| format!("{} ({})", file.name(), file.size()))
+					.collect::<Vec<_>>()
+					.join(", "),
+			);
+		};
+		view! {
+			<label for="avatar">"Avatar"</label>
+			<input id="avatar" type="file" accept="image/*" on:change=on_change />
+			<label>"Documents" <input type="file" accept=".csv,.txt" multiple on:change=on_change /></label>
+			<input aria-label="Disabled" type="file" disabled on:change=on_change />
+			<p id="names">{names}</p>
+		}
+	});
+	let names = || render.get_by_id("names").unwrap().display_text();
+	let png = UploadFile::new("avatar.png", vec![1, 2, 3], "image/png");
+	let csv = UploadFile::new("data.csv", "a,b\n1,2", "text/csv");
+	let notes = UploadFile::new("notes.txt", "hello", "text/plain");
+
+	user_event::upload(
+		&render.get_by_label("Avatar").unwrap(),
+		&[csv.clone(), png.clone()],
+	)
+	.unwrap();
+	tick().await;
+	assert_eq!(names(), "avatar.png (3)");
+
+	let documents = render.get_by_text("Documents").unwrap();
+	user_event::upload(&documents, &[csv, png.clone(), notes]).unwrap();
+	tick().await;
+	assert_eq!(names(), "data.csv (8), notes.txt (5)");
+
+	user_event::upload(&render.get_by_label("Disabled").unwrap(), &[png.clone()]).unwrap();
+	tick().await;
+	assert_eq!(names(), "data.csv (8), notes.txt (5)");
+
+	assert!(matches!(
+		user_event::upload(&render.get_by_id("names").unwrap(), &[png]),
+		Err(TestingLibraryError::NotFileInput { .. })
+	));
+}
+
+#[wasm_bindgen_test]
+pub async fn user_event_drag_and_drop() {
+	let render = render_for_test(|| {
+		let done = RwSignal::new(Vec::<String>::new());
+		let events = RwSignal::new(Vec::<String>::new());
+		let log = move |name: &str| events.update(|events| events.push(name.to_string()));
+		view! {
+			<div
+				draggable="true"
+				on:dragstart=move |event: leptos::ev::DragEvent| {
+					log("dragstart");
+					_ = event.data_transfer().unwrap().set_data("text/plain", "Write tests");
+				}
+				on:dragend=move |_| log("dragend")
+			>
+				"Write tests"
+			</div>
+			<section
+				aria-label="Done"
+				on:dragenter=move |_| log("dragenter")
+				on:dragover=move |event: leptos::ev::DragEvent| {
+					log("dragover");
+					event.prevent_default();
+				}
+				on:drop=move |event: leptos::ev::DragEvent| {
+					log("drop");
+					let card = event.data_transfer().unwrap().get_data("text/plain").unwrap();
+					done.update(|done| done.push(card));
+				}
+			>
+				<For each=move || done.get() key=Clone::clone let:card>
+					<p>{card}</p>
+				</For>
+			</section>
+			<section aria-label="Archive" on:dragleave=move |_| log("dragleave") />
+			<p id="events">{move || events.get().join(", ")}</p>
+			<button on:click=move |_| events.set(Vec::new())>"Clear"</button>
+		}
+	});
+	let events = || render.get_by_id("events").unwrap().display_text();
+	let card = render.get_by_text("Write tests").unwrap();
+
+	user_event::drag_and_drop(&card, &render.get_by_label("Done").unwrap()).unwrap();
+	tick().await;
+	assert_eq!(events(), "dragstart, dragenter, dragover, drop, dragend");
+	assert_eq!(render.get_all_by_text("Write tests").len(), 2);
+
+	user_event::click(&render.get_by_text("Clear").unwrap()).unwrap();
+	user_event::drag_and_drop(&card, &render.get_by_label("Archive").unwrap()).unwrap();
+	tick().await;
+	assert_eq!(events(), "dragstart, dragleave, dragend");
+}
+
+#[wasm_bindgen_test]
+pub fn user_event_clipboard() {
+	let render = render_for_test(|| {
+		view! {
+			<input aria-label="Source" value="Hello world" />
+			<textarea aria-label="Target"></textarea>
+			<input
+				aria-label="Custom"
+				value="secret"
+				on:copy=move |event| {
+					event.prevent_default();
+					let event = event.unchecked_into::<web_sys::ClipboardEvent>();
+					_ = event.clipboard_data().unwrap().set_data("text/plain", "redacted");
+				}
+			/>
+		}
+	});
+	let value = |label: &str| {
+		let element = render.get_by_label(label).unwrap();
+		element.dyn_ref::<web_sys::HtmlInputElement>().map_or_else(
+			|| {
+				element
+					.unchecked_ref::<web_sys::HtmlTextAreaElement>()
+					.value()
+			},
+			web_sys::HtmlInputElement::value,
+		)
+	};
+
+	let source = render.get_by_label("Source").unwrap();
+	user_event::click(&source).unwrap();
+	let input = source.unchecked_ref::<web_sys::HtmlInputElement>();
+	input.set_selection_range(0, 5).unwrap();
+	assert_eq!(user_event::copy(), "Hello");
+	assert_eq!(value("Source"), "Hello world");
+
+	input.set_selection_range(5, 11).unwrap();
+	assert_eq!(user_event::cut(), " world");
+	assert_eq!(value("Source"), "Hello");
+	assert_eq!(user_event::read_clipboard(), " world");
+
+	user_event::click(&render.get_by_label("Target").unwrap()).unwrap();
+	user_event::paste_from_clipboard();
+	assert_eq!(value("Target"), " world");
+
+	let custom = render.get_by_label("Custom").unwrap();
+	user_event::click(&custom).unwrap();
+	custom.unchecked_ref::<web_sys::HtmlInputElement>().select();
+	assert_eq!(user_event::copy(), "redacted");
+
+	user_event::write_clipboard("!");
+	user_event::click(&render.get_by_label("Target").unwrap()).unwrap();
+	user_event::paste_from_clipboard();
+	assert_eq!(value("Target"), " world!");
+}
+
+#[wasm_bindgen_test]
+pub fn assertions() {
+	let render = render_for_test(|| {
+		view! {
+			<button class="btn primary" style="color: red">"Save"</button>
+			<button disabled>"Delete"</button>
+			<div role="button" aria-disabled="true">"Archive"</div>
+			<input type="checkbox" aria-label="Done" checked />
+			<div role="switch" aria-checked="false" aria-label="Dark mode"></div>
+			<input aria-label="Name" value="Ada" />
+			<p hidden>"Secret"</p>
+			<div id="empty">{None::<String>}</div>
+		}
+	});
+	let save = render.get_by_text("Save").unwrap();
+
+	expect(&save).to_be_visible();
+	expect(&save).to_be_enabled();
+	expect(&save).not().to_be_disabled();
+	expect(&save).to_have_class("primary btn");
+	expect(&save).not().to_have_class("secondary");
+	expect(&save).to_have_attribute("style");
+	expect(&save).to_have_attribute_value("class", "btn primary");
+	expect(&save).to_have_style("color: red");
+	expect(&save).not().to_have_style("color: blue");
+	expect(&save).to_have_text_content("Save");
+	expect(&save).to_have_accessible_name("Save");
+	expect(&save).to_be_in_the_document();
+	expect(&save).not().to_have_focus();
+	save.focus().unwrap();
+	expect(&save).to_have_focus();
+
+	expect(&render.get_by_text("Delete").unwrap()).to_be_disabled();
+	expect(&render.get_by_text("Archive").unwrap()).to_be_disabled();
+	expect(&render.get_by_label("Done").unwrap()).to_be_checked();
+	expect(&render.get_by_label("Dark mode").unwrap())
+		.not()
+		.to_be_checked();
+	expect(&render.get_by_label("Name").unwrap()).to_have_value("Ada");
+	expect(&render.get_by_text("Secret").unwrap())
+		.not()
+		.to_be_visible();
+	expect(&render.get_by_id("empty").unwrap()).to_be_empty_dom_element();
+	expect(&render.get_by_text("Save").unwrap())
+		.not()
+		.to_be_empty_dom_element();
+
+	let removed = render.get_by_text("Delete").unwrap();
+	removed.remove();
+	expect(&removed).not().to_be_in_the_document();
+	expect(&removed).not().to_be_visible();
+}
+
+#[wasm_bindgen_test]
+#[should_panic(
+	expected = "expect(element).not().to_have_class(\"primary\")\n\nReceived: the element has the \
+	            classes \"btn primary\"\n\n<button class=\"btn primary\">\n  Save\n</button>"
+)]
+pub fn assertion_failure_message() {
+	let render = render_for_test(|| view! { <button class="btn primary">"Save"</button> });
+	expect(&render.get_by_text("Save").unwrap())
+		.not()
+		.to_have_class("primary");
+}
+
+#[wasm_bindgen_test]
+pub fn pretty_dom_formats_elements() {
+	let count = RwSignal::new(1);
+	let render = render_for_test(move || {
+		view! {
+			<form title="Counter" aria-label="Count">
+				<input type="number" name="count" />
+				<p>"Count: " {move || count.get()}</p>
+			</form>
+		}
+	});
+	let form = render.get_by_role("form").unwrap();
+
+	assert_eq!(
+		pretty_dom(&form, &PrettyOptions::default()),
+		"<form aria-label=\"Count\" title=\"Counter\">\n  <input name=\"count\" \
+		 type=\"number\">\n  <p>\n    Count:\n    1\n  </p>\n</form>"
+	);
+	assert_eq!(
+		pretty_dom(
+			&form,
+			&PrettyOptions {
+				indent: 4,
+				sort_attributes: false,
+				max_length: 30,
+				..Default::default()
+			}
+		),
+		"<form title=\"Counter\" aria-lab..."
+	);
+	assert!(
+		pretty_dom(
+			&form,
+			&PrettyOptions {
+				highlight: true,
+				..Default::default()
+			}
+		)
+		.starts_with("\x1b[36m<form\x1b[0m \x1b[33maria-label\x1b[0m=\x1b[32m\"Count\"\x1b[0m")
+	);
+
+	let quote = document().create_element("q").unwrap();
+	quote.set_attribute("title", r#""Tom" & <Jerry>"#).unwrap();
+	quote.set_text_content(Some(r#"1 < 2 & "3""#));
+	assert_eq!(
+		pretty_dom(&quote, &PrettyOptions::default()),
+		"<q title=\"&quot;Tom&quot; &amp; &lt;Jerry>\">\n  1 &lt; 2 &amp; &quot;3&quot;\n</q>"
+	);
+}
+
+#[wasm_bindgen_test]
+pub fn dom_snapshot_is_normalized() {
+	let render = render_for_test(|| {
+		view! {
+			<label for="email-17">"Email"</label>
+			<input
+				type="email"
+				id="email-17"
+				aria-describedby="email-hint email-17-error"
+				placeholder="you@example.com"
+			/>
+			<p id="email-hint">"We'll never   share it."</p>
+			<p id="email-17-error">"Required"</p>
+		}
+	});
+
+	assert_dom_snapshot!(render, @r#"
+	<div>
+	  <label for="id-1">
+	    Email
+	  </label>
+	  <input aria-describedby="email-hint id-2" id="id-1" placeholder="you@example.com" type="email">
+	  <p id="email-hint">
+	    We'll never share it.
+	  </p>
+	  <p id="id-2">
+	    Required
+	  </p>
+	</div>
+	"#);
+}
+
+#[wasm_bindgen_test]
+pub fn suggested_query_prefers_accessible_queries() {
+	let render = render_for_test(|| {
+		view! {
+			<label for="email">"Email"</label>
+			<input id="email" data-testid="email" type="email" />
+			<input data-testid="search" placeholder="Search" />
+			<span data-testid="status">"Saved"</span>
+			<span data-testid="first">"Item"</span>
+			<span data-testid="second">"Item"</span>
+			<button data-testid="save">"Save"</button>
+		}
+	});
+
+	let suggested = |test_id: &str| render.get_by_test_id(test_id).unwrap().suggested_query();
+	assert_eq!(
+		suggested("save"),
+		Some(SuggestedQuery {
+			kind: QueryKind::Role,
+			text: "button".into(),
+			name: Some("Save".into()),
+		})
+	);
+	assert_eq!(
+		suggested("email").unwrap().to_string(),
+		r#"get_by_role_with("textbox", ByRoleOptions::default().name("Email"))"#
+	);
+	assert_eq!(
+		suggested("search").unwrap().to_string(),
+		r#"get_by_role_with("textbox", ByRoleOptions::default().name("Search"))"#
+	);
+	assert_eq!(
+		suggested("status").unwrap().to_string(),
+		r#"get_by_text("Saved")"#
+	);
+	assert_eq!(
+		suggested("first").unwrap().to_string(),
+		r#"get_by_test_id("first")"#
+	);
+
+	let _config = RestoreConfig::new();
+	configure(|config| config.warn_on_lower_priority_queries = true);
+	assert!(render.get_by_test_id("save").is_ok());
+}